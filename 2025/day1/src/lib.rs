@@ -0,0 +1,96 @@
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+type Input = Vec<i64>;
+
+fn part1(input: &Input) -> usize {
+    input.iter().scan(50i64, |state, &n| {
+        *state = (*state + n).rem_euclid(100);
+        Some(*state)
+    }).filter(|&n| n == 0)
+    .count()
+}
+
+fn part2(input: &Input) -> usize {
+    let mut dial = 50;
+    let mut clicks = 0;
+    for &n in input {
+        dial += n;
+        if dial <= 0 && n != dial {
+            clicks += 1;
+        }
+        clicks += dial.abs() / 100;
+        dial = dial.rem_euclid(100);
+    }
+    clicks as usize
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (dir, n) = line.split_at(line.chars().next().map_or(0, char::len_utf8));
+            let sign = match dir {
+                "L" => -1,
+                "R" => 1,
+                _ => return Err(source.error(dir, "`L` or `R`")),
+            };
+            Ok(sign * source.parse::<i64>(n, "a number of clicks")?)
+        })
+        .collect()
+}
+
+#[test]
+fn test1() {
+    let input = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+    assert_eq!(3, part1(&parse_input(input).unwrap()));
+}
+
+#[test]
+fn test2() {
+    let input = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+    assert_eq!(6, part2(&parse_input(input).unwrap()));
+}
\ No newline at end of file
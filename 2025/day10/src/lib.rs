@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+use utils::answer::Answer;
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+
+pub struct Machine {
+    light_goal : String,
+    buttons : Vec<Vec<u64>>,
+    joltage : Vec<u64>
+}
+
+impl Debug for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\n  Machine {{ light_goal: {}, buttons: {:?}, joltage: {:?} }}",
+            self.light_goal, self.buttons, self.joltage
+        )
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let source = Source::new(input);
+    let numbers = |list: &str, expected: &str| -> Result<Vec<u64>, ParseError> {
+        list.split(',').map(|n| source.parse(n, expected)).collect()
+    };
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let lights = source.next(&mut parts, line, "the lights")?;
+            if !(lights.starts_with('[') && lights.ends_with(']') && lights.len() >= 2)
+                || lights[1..lights.len() - 1].contains(|c| c != '.' && c != '#')
+            {
+                return Err(source.error(lights, "lights like `[.##.]`"));
+            }
+            let mut buttons: Vec<Vec<u64>> = Vec::new();
+            let mut joltage: Vec<u64> = Vec::new();
+            for part in parts {
+                if let Some(inner) = part.strip_prefix('(').and_then(|part| part.strip_suffix(')')) {
+                    buttons.push(numbers(inner, "a light number")?);
+                } else if let Some(inner) = part.strip_prefix('{').and_then(|part| part.strip_suffix('}')) {
+                    joltage = numbers(inner, "a joltage")?;
+                } else {
+                    return Err(source.error(part, "a button like `(1,3)` or joltages like `{3,5}`"));
+                }
+            }
+            Ok(Machine { light_goal: lights.to_string(), buttons, joltage })
+        })
+        .collect()
+}
+
+fn part1(_input: &[Machine]) -> Answer {
+    Answer::Unsolved
+}
+
+fn part2(_input: &[Machine]) -> Answer {
+    Answer::Unsolved
+}
+
+#[test]
+fn test1() {
+    let raw_input = r"
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+    assert_eq!(part1(&parse_input(raw_input).unwrap()), Answer::from(7));
+}
+
+#[test]
+fn test2() {
+    let raw_input = r"
+";
+    assert_eq!(part2(&parse_input(raw_input).unwrap()), Answer::Unsolved);
+}
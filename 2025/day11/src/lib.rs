@@ -0,0 +1,53 @@
+use utils::answer::Answer;
+use utils::parse::ParseError;
+use utils::solution::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<String>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn part1(_input: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+fn part2(_input: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+#[test]
+fn test1() {
+    let raw_input = r"
+";
+    assert_eq!(part1(&parse_input(raw_input)), Answer::Unsolved);
+}
+
+#[test]
+fn test2() {
+    let raw_input = r"
+";
+    assert_eq!(part2(&parse_input(raw_input)), Answer::Unsolved);
+}
@@ -0,0 +1,117 @@
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .flat_map(|line| line.split(','))
+        .map(|part| {
+            let mut range = part.split('-');
+            let start = source.next(&mut range, part, "a range")?;
+            let end = source.next(&mut range, start, "`-` and the end of the range")?;
+            Ok((source.parse(start, "a number")?, source.parse(end, "a number")?))
+        })
+        .collect()
+}
+
+fn part1(input: &Vec<(u64, u64)>) -> u64 {
+    let mut count = 0;
+    for &(start, end) in input {
+        let numbers: Vec<String> = (start..=end)
+            .map(|n| n.to_string())
+            .filter(|s| s.len().rem_euclid(2) == 0)
+            .collect::<Vec<String>>();
+        let sum = numbers
+            .iter()
+            .map(|s| {
+                let left = s
+                    .chars()
+                    .take(s.len() / 2)
+                    .collect::<String>()
+                    .parse::<u64>()
+                    .unwrap();
+                let right = s
+                    .chars()
+                    .skip(s.len() / 2)
+                    .collect::<String>()
+                    .parse::<u64>()
+                    .unwrap();
+                if left == right {
+                    s.parse::<u64>().unwrap()
+                } else {
+                    0
+                }
+            })
+            .sum::<u64>();
+        count += sum;
+    }
+    count
+}
+
+fn part2(input: &Vec<(u64, u64)>) -> u64 {
+    let mut count = 0;
+    for &(start, end) in input {
+        let numbers: Vec<String> = (start..=end)
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>();
+        for s in numbers {
+            let mut window_size = if s.len().rem_euclid(2) == 0 {
+                s.len() / 2
+            } else {
+                s.len().div_ceil(2)
+            };
+            while window_size > 0 {
+                let chunks: Vec<&str> = s
+                .as_bytes()
+                .chunks(window_size)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap())
+                .collect();
+                
+                window_size -= 1;
+                
+                if chunks.len() < 2 {
+                    continue;
+                }
+
+                if chunks.iter().skip(1).all(|&c| c == chunks[0]) {
+                    count += s.parse::<u64>().unwrap();
+                    break;
+                }
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test1() {
+    let input = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862";
+    assert_eq!(1227775554, part1(&parse_input(input).unwrap()));
+}
+
+#[test]
+fn test2() {
+    let input = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+    assert_eq!(4174379265, part2(&parse_input(input).unwrap()));
+}
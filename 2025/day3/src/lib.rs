@@ -0,0 +1,99 @@
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input, 12)
+    }
+}
+
+/// Reads each bank as the joltage digits of its batteries
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let source = Source::new(input);
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| source.error(&line[index..index + c.len_utf8()], "a digit"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn part1(input: &[Vec<u32>]) -> u64 {
+    input.iter()
+        .map(|digits| {
+            let mut largest_num: u32 = 0;
+            for i in 0..digits.len() {
+                for j in (i+1)..digits.len() {
+                    if (digits[i] * 10 + digits[j]) > largest_num{
+                        largest_num = digits[i] * 10 + digits[j];
+                    }
+                }
+            }
+            largest_num as u64
+        }).sum()  
+}
+
+fn part2(input: &[Vec<u32>], num_batteries: usize) -> u64 {
+    input.iter()
+        .map(|digits| {
+            if digits.len() < num_batteries {
+                return 0;
+            }
+            let mut turned_on : Vec<u32> = digits[0..num_batteries].to_vec();
+            for window in digits.windows(num_batteries).skip(1) {
+                for i in 0..num_batteries {
+                    if window[i] > turned_on[i] {
+                        turned_on.truncate(i);
+                        turned_on.append(&mut window[i..].to_vec());
+                        break;
+                    }
+                }
+            }
+            turned_on.iter().fold(0u64, |acc, &d| acc * 10 + d as u64)
+        }).sum()
+}
+
+#[test]
+fn test1()
+{
+    let input = r"
+    987654321111111
+    811111111111119
+    234234234234278
+    818181911112111
+    ";
+    assert_eq!(357, part1(&parse_input(input).unwrap()));
+}
+
+#[test]
+fn test2()
+{
+    let input = r"
+    987654321111111
+    811111111111119
+    234234234234278
+    818181911112111
+    ";
+    assert_eq!(3121910778619, part2(&parse_input(input).unwrap(), 12));
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use utils::parse::ParseError;
+use utils::solution::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect()
+}
+
+fn part1(input: &[Vec<char>]) -> u64 {
+    let mut count = 0;
+    let rows = input.len();
+    let cols = input[0].len();
+
+    for r in 0..rows {
+        for c in 0..cols {
+            if input[r][c] == '@' {
+                let mut adjacent_count = 0;
+                for dr in -1..=1 {
+                    for dc in -1..=1 {
+                        if dr == 0 && dc == 0 {
+                            continue;
+                        }
+                        let nr = r as isize + dr;
+                        let nc = c as isize + dc;
+                        if nr >= 0 && nr < rows as isize && nc >= 0 && nc < cols as isize
+                            && input[nr as usize][nc as usize] == '@'
+                        {
+                            adjacent_count += 1;
+                        }
+                    }
+                }
+                if adjacent_count < 4 {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+fn part2(input: &[Vec<char>]) -> i64 {
+    let mut count = 0;
+    let mut input_clone = input.to_vec();
+    let rows = input_clone.len();
+    let cols = input_clone[0].len();
+
+    let mut prev_count: i64 = -1;
+    while prev_count != count {
+        prev_count = count;
+        for r in 0..rows {
+            for c in 0..cols {
+                if input_clone[r][c] == '@' {
+                    let mut adjacent_count = 0;
+                    for dr in -1..=1 {
+                        for dc in -1..=1 {
+                            if dr == 0 && dc == 0 {
+                                continue;
+                            }
+                            let nr = r as isize + dr;
+                            let nc = c as isize + dc;
+                            if nr >= 0 && nr < rows as isize && nc >= 0 && nc < cols as isize
+                                && input_clone[nr as usize][nc as usize] == '@'
+                            {
+                                adjacent_count += 1;
+                            }
+                        }
+                    }
+                    if adjacent_count < 4 {
+                        count += 1;
+                        input_clone[r][c] = '.';                    }
+                }
+            }
+        }
+    }
+
+    count
+}
+
+#[test]
+fn test1() {
+    let input = "..@@.@@@@.
+    @@@.@.@.@@
+    @@@@@.@.@@
+    @.@@@@..@.
+    @@.@@@@.@@
+    .@@@@@@@.@
+    .@.@.@.@@@
+    @.@@@.@@@@
+    .@@@@@@@@.
+    @.@.@@@.@.";
+    let parsed_input = parse_input(input);
+    assert_eq!(part1(&parsed_input), 13);
+}
+
+#[test]
+fn test2() {
+    let input = "..@@.@@@@.
+                       @@@.@.@.@@
+                       @@@@@.@.@@
+                       @.@@@@..@.
+                       @@.@@@@.@@
+                       .@@@@@@@.@
+                       .@.@.@.@@@
+                       @.@@@.@@@@
+                       .@@@@@@@@.
+                       @.@.@@@.@.";
+    let parsed_input = parse_input(input);
+    assert_eq!(part2(&parsed_input), 43);
+}
\ No newline at end of file
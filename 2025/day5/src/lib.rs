@@ -0,0 +1,166 @@
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+use regex::Regex;
+
+pub struct Interval {
+    start: u64,
+    end: u64,
+}
+
+struct Union {
+    intervals: Vec<Interval>,
+}
+
+impl Union {
+    fn new() -> Self {
+        Union { intervals: vec![] }
+    }
+
+    fn add(&mut self, mut new_interval: Interval) {
+        let mut i = 0;
+        while i < self.intervals.len() {
+            let current = &self.intervals[i];
+            if new_interval.end < current.start {
+                break;
+            } else if new_interval.start > current.end {
+                i += 1;
+                continue;
+            } else {
+                new_interval.start = new_interval.start.min(current.start);
+                new_interval.end = new_interval.end.max(current.end);
+                self.intervals.remove(i);
+            }
+        }
+        self.intervals.insert(i, new_interval);
+    }
+
+    fn total_covered(&self) -> u64 {
+        self.intervals.iter().map(|interval| interval.end - interval.start + 1).sum()
+    }
+
+    fn count_covered(&self, queries: &Vec<u64>) -> u64 {
+        let mut count = 0;
+        for &query in queries {
+            for interval in &self.intervals {
+                if query >= interval.start && query <= interval.end {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+        count
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Interval>, Vec<u64>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Interval>, Vec<u64>), ParseError> {
+    let source = Source::new(input);
+    let re = Regex::new(r"\n\s*\n").unwrap();
+    let input = input.trim();
+    let parts: Vec<&str> = re.split(input).collect();
+    let Some(queries) = parts.get(1) else {
+        return Err(source.missing_after(input, "a blank line followed by the ingredient IDs"));
+    };
+
+    let intervals = parts[0]
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let mut nums = line.split('-');
+            let start = source.next(&mut nums, line, "a range")?;
+            let end = source.next(&mut nums, start, "`-` and the end of the range")?;
+            Ok(Interval {
+                start: source.parse(start, "a number")?,
+                end: source.parse(end, "a number")?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let queries = queries
+        .lines()
+        .map(|line| source.parse(line.trim(), "an ingredient ID"))
+        .collect::<Result<_, _>>()?;
+    
+    Ok((intervals, queries))
+}
+
+fn part1(input: &(Vec<Interval>, Vec<u64>)) -> usize {
+    let (intervals, queries) = input;
+    let mut union : Union = Union::new();
+
+    for interval in intervals {
+        union.add(Interval {
+            start: interval.start,
+            end: interval.end,
+        });
+    }
+
+    union.count_covered(queries) as usize
+}
+
+fn part2(input: &(Vec<Interval>, Vec<u64>)) -> usize {
+    let (intervals, _) = input;
+    let mut union : Union = Union::new();
+
+    for interval in intervals {
+        union.add(Interval {
+            start: interval.start,
+            end: interval.end,
+        });
+    }
+
+    union.total_covered() as usize
+}
+
+#[test]
+fn test1() {
+    let input = r"3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32
+    ";
+    assert_eq!(part1(&parse_input(input).unwrap()), 3);
+}
+
+#[test]
+fn test2() {
+    let input = r"3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32
+    ";
+    assert_eq!(part2(&parse_input(input).unwrap()), 14);
+}
\ No newline at end of file
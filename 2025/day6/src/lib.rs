@@ -0,0 +1,150 @@
+use utils::parse::{ParseError, Source};
+use utils::solution::Solution;
+
+/// The rows of numbers, kept as text since the parts read them in different
+/// directions, and the operator of each problem
+pub struct Worksheet {
+    rows: Vec<String>,
+    operators: Vec<char>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Worksheet, ParseError> {
+    let source = Source::new(input);
+    let mut lines: Vec<&str> = input.lines().filter(|line| !line.trim().is_empty()).collect();
+    let Some(operators_line) = lines.pop() else {
+        return Err(source.error(input.trim(), "rows of numbers followed by a line of operators"));
+    };
+    let operators = operators_line
+        .split_whitespace()
+        .map(|operator| match operator {
+            "+" => Ok('+'),
+            "*" => Ok('*'),
+            _ => Err(source.error(operator, "`+` or `*`")),
+        })
+        .collect::<Result<Vec<char>, _>>()?;
+    for line in &lines {
+        if let Some((index, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit() && !c.is_whitespace()) {
+            return Err(source.error(&line[index..index + c.len_utf8()], "a digit"));
+        }
+        if line.split_whitespace().count() != operators.len() {
+            return Err(source.error(line.trim_end(), format!("{} numbers, one per operator", operators.len())));
+        }
+    }
+    Ok(Worksheet {
+        rows: lines.into_iter().map(str::to_string).collect(),
+        operators,
+    })
+}
+
+/// Reads digits checked by [`parse_input`] as a number
+fn number(digits: &str) -> u64 {
+    digits.bytes().fold(0, |n, digit| n * 10 + u64::from(digit - b'0'))
+}
+
+/// Columns left to right, each row holding a complete number
+fn part1(sheet: &Worksheet) -> u64 {
+    let matrix: Vec<Vec<&str>> = sheet.rows.iter().map(|row| row.split_whitespace().collect()).collect();
+    sheet
+        .operators
+        .iter()
+        .enumerate()
+        .map(|(col, operator)| {
+            let numbers = matrix.iter().map(|row| number(row[col]));
+            match operator {
+                '+' => numbers.sum::<u64>(),
+                _ => numbers.product::<u64>(),
+            }
+        })
+        .sum()
+}
+
+/// Columns right to left, the digits of each number written vertically
+fn part2(sheet: &Worksheet) -> u64 {
+    let mut operators_line = sheet.operators.clone();
+
+    // Pre-convert to char grids for O(1) access
+    let grid: Vec<Vec<char>> = sheet.rows.iter().map(|l| l.chars().collect()).collect();
+    let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut problems: u64 = 0;
+    let Some(mut current_operator) = operators_line.pop() else {
+        return 0;
+    };
+    let mut current_equation: Vec<String> = Vec::new();
+    let mut current_num: String = String::new();
+    for column_number in (0..width).rev() {
+        for row in &grid {
+            // O(1) access instead of O(n)
+            let ch = row.get(column_number).copied().unwrap_or(' ');
+            if !ch.is_whitespace() {
+                current_num.push(ch);
+            }
+        }
+        if current_num.is_empty() || column_number == 0 {
+            if column_number == 0 && !current_num.is_empty() {
+                current_equation.push(current_num.clone());
+            }
+
+            match current_operator {
+                '+' => {
+                    let eq_eval = current_equation.iter().map(|s| number(s)).sum::<u64>();
+                    problems += eq_eval;
+                },
+                _ => {
+                    let eq_eval = current_equation.iter().map(|s| number(s)).product::<u64>();
+                    problems += eq_eval;
+                },
+            }
+            if let Some(op) = operators_line.pop() {
+                current_operator = op;
+                current_equation.clear();
+            }
+        } else {
+            current_equation.push(current_num.clone());
+            current_num.clear();
+        }
+    }
+    problems
+}
+
+#[test]
+fn test1() {
+    let raw_input = r"
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +
+";
+    assert_eq!(part1(&parse_input(raw_input).unwrap()), 4277556);
+}
+
+#[test]
+fn test2() {
+    let raw_input = r"
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+    assert_eq!(part2(&parse_input(raw_input).unwrap()), 3263827);
+}
\ No newline at end of file
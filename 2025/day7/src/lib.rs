@@ -0,0 +1,118 @@
+use utils::parse::ParseError;
+use utils::solution::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    let lines: Vec<&str> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    lines.iter().map(|f| f.chars().collect()).collect()
+}
+
+fn part1(tachyon_map: &[Vec<char>]) -> u64 {
+    let mut beam_splits: u64 = 0;
+    // Which columns a beam reaches in the current row, swept top to bottom like part 2
+    let mut beams: Vec<bool> = vec![false; tachyon_map[0].len()];
+    for row in tachyon_map {
+        for (index, space) in row.iter().enumerate() {
+            match space {
+                'S' => beams[index] = true,
+                '^' if beams[index] => {
+                    beam_splits += 1;
+                    beams[index - 1] = true;
+                    beams[index + 1] = true;
+                    beams[index] = false;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    beam_splits
+}
+
+fn part2(tachyon_map: &[Vec<char>]) -> u64 {
+    let mut total_paths: Vec<u64> = vec![0; tachyon_map[0].len()];
+    for row in tachyon_map {
+        for (index, space) in row.iter().enumerate() {
+            match space {
+                'S' => total_paths[index] = 1,
+                '^' => {
+                    total_paths[index - 1] += total_paths[index];
+                    total_paths[index + 1] += total_paths[index];
+
+                    total_paths[index] = 0;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    total_paths.iter().sum()
+}
+
+#[test]
+fn test1() {
+    let input = r"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+    assert_eq!(part1(&parse_input(input)), 21);
+}
+
+#[test]
+fn test2() {
+    let input = r"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+    assert_eq!(part2(&parse_input(input)), 40);
+}
@@ -0,0 +1,24 @@
+#[path = "day1/src/lib.rs"]
+pub mod day1;
+#[path = "day2/src/lib.rs"]
+pub mod day2;
+#[path = "day3/src/lib.rs"]
+pub mod day3;
+#[path = "day4/src/lib.rs"]
+pub mod day4;
+#[path = "day5/src/lib.rs"]
+pub mod day5;
+#[path = "day6/src/lib.rs"]
+pub mod day6;
+#[path = "day7/src/lib.rs"]
+pub mod day7;
+#[path = "day8/src/lib.rs"]
+pub mod day8;
+#[path = "day9/src/lib.rs"]
+pub mod day9;
+#[path = "day10/src/lib.rs"]
+pub mod day10;
+#[path = "day11/src/lib.rs"]
+pub mod day11;
+#[path = "day12/src/lib.rs"]
+pub mod day12;
@@ -0,0 +1,291 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use utils::bench::Bench;
+use utils::structs::stopwatch::{ReportFormat, Reported};
+
+use crate::output::Output;
+use crate::runner::{self, BenchOptions, RunOptions};
+use crate::summary::{self, AllOptions};
+use crate::registry::REGISTRY;
+use crate::{doctor, download, readme, scaffold, verify, watch, worker};
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [<path>|-|--input <path>|--example <n>] [--spans] [--trace <path>]
+                 [--unit <auto|ns|μs|ms|s>] [--precision <auto|cycles|decimals>]
+                 [--timeout <duration>] [--output <text|json|csv>]
+       aoc run --all [--year <year>] [--days <day|first-last>] [--jobs <n>] [--spans] [--trace <path>]
+                 [--unit <auto|ns|μs|ms|s>] [--precision <auto|cycles|decimals>]
+                 [--timeout <duration>] [--output <text|json|csv>]
+       aoc bench <year> <day> [--input <path>|--example <n>] [--warmup <n>] [--iterations <n>]
+                 [--save <baseline>] [--compare <baseline>] [--threshold <percent>]
+       aoc watch <year> <day>
+       aoc download <year> <day>
+       aoc download --all [--year <year>]
+       aoc new <year> <day>
+       aoc doctor
+       aoc verify
+       aoc readme";
+
+/// Default `--threshold` of `aoc bench`, in percent
+const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Command-line arguments, consumed option by option
+pub struct Args(Vec<String>);
+
+impl Args {
+    /// Collects the arguments passed to the current process, without the program name
+    pub fn from_env() -> Self {
+        Self(std::env::args().skip(1).collect())
+    }
+
+    /// Removes `name <value>` or `name=<value>` and returns the value
+    pub fn option(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{name}=");
+        let Some(index) = self
+            .0
+            .iter()
+            .position(|arg| arg == name || arg.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+        let arg = self.0.remove(index);
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Ok(Some(value.to_string()));
+        }
+        if index < self.0.len() {
+            Ok(Some(self.0.remove(index)))
+        } else {
+            Err(format!("{name} expects a value"))
+        }
+    }
+
+    /// Removes `name <number>` or `name=<number>` and returns the number
+    pub fn number<T: std::str::FromStr>(&mut self, name: &str) -> Result<Option<T>, String> {
+        self.option(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("{name} expects a number, got {value}"))
+            })
+            .transpose()
+    }
+
+    /// Removes `name` and returns whether it was present
+    pub fn flag(&mut self, name: &str) -> bool {
+        let before = self.0.len();
+        self.0.retain(|arg| arg != name);
+        self.0.len() != before
+    }
+
+    /// Removes the leading command name, if the first argument isn't an option
+    pub fn command(&mut self) -> Option<String> {
+        match self.0.first() {
+            Some(arg) if !arg.starts_with("--") => Some(self.0.remove(0)),
+            _ => None,
+        }
+    }
+
+    /// Removes every remaining argument, failing on options nobody consumed
+    pub fn finish(self) -> Result<Vec<String>, String> {
+        match self.0.iter().find(|arg| arg.starts_with("--")) {
+            Some(arg) => Err(format!("unknown option {arg}")),
+            None => Ok(self.0),
+        }
+    }
+}
+
+/// Entry point of the `aoc` binary
+pub fn main() -> ExitCode {
+    worker::serve_if_requested();
+    dispatch(Args::from_env()).unwrap_or_else(|err| usage(&err))
+}
+
+fn dispatch(mut args: Args) -> Result<ExitCode, String> {
+    let command = args.command().ok_or("missing command")?;
+    match command.as_str() {
+        "run" if args.flag("--all") => {
+            let mut all = AllOptions {
+                year: args.number("--year")?,
+                ..AllOptions::default()
+            };
+            if let Some(days) = args.option("--days")? {
+                all.days = summary::parse_days(&days)?;
+            }
+            all.jobs = args.number("--jobs")?.unwrap_or(all.jobs);
+            if all.jobs == 0 {
+                return Err("--jobs must be at least 1".to_string());
+            }
+            let options = run_options(&mut args)?;
+            if options.input.is_some() || options.example.is_some() {
+                return Err("--input and --example pick the input of a single day, not --all".to_string());
+            }
+            all.spans = options.spans;
+            all.trace = options.trace;
+            all.format = options.format;
+            all.timeout = options.timeout;
+            all.output = options.output;
+            no_arguments(args)?;
+            Ok(summary::run_all(&all))
+        }
+        "run" => {
+            let mut options = run_options(&mut args)?;
+            let (year, day, input) = match args.finish()?.as_slice() {
+                [year, day, input] => {
+                    let (year, day) = year_day(&[year.clone(), day.clone()])?;
+                    (year, day, Some(input.clone()))
+                }
+                rest => year_day(rest).map(|(year, day)| (year, day, None))?,
+            };
+            positional_input(&mut options, input)?;
+            Ok(runner::run(year, day, &options))
+        }
+        "bench" => {
+            let mut bench = Bench::default();
+            bench.warmup = args.number("--warmup")?.unwrap_or(bench.warmup);
+            bench.iterations = args.number("--iterations")?.unwrap_or(bench.iterations);
+            if bench.iterations == 0 {
+                return Err("--iterations must be at least 1".to_string());
+            }
+            let threshold: f64 = args.number("--threshold")?.unwrap_or(DEFAULT_THRESHOLD);
+            if !threshold.is_finite() || threshold < 0.0 {
+                return Err("--threshold must be a non-negative percentage".to_string());
+            }
+            let (input, example) = input_options(&mut args)?;
+            let options = BenchOptions {
+                input,
+                example,
+                bench,
+                save: args.option("--save")?,
+                compare: args.option("--compare")?,
+                threshold: threshold / 100.0,
+            };
+            let (year, day) = year_day(&args.finish()?)?;
+            Ok(runner::bench(year, day, &options))
+        }
+        "watch" => {
+            let (year, day) = year_day(&args.finish()?)?;
+            Ok(watch::watch(year, day))
+        }
+        "download" if args.flag("--all") => {
+            let year: Option<u16> = args.number("--year")?;
+            no_arguments(args)?;
+            let days: Vec<(u16, u8)> = REGISTRY
+                .iter()
+                .filter(|entry| year.is_none_or(|year| entry.year == year))
+                .map(|entry| (entry.year, entry.day))
+                .collect();
+            if days.is_empty() {
+                return Err("no registered solutions match".to_string());
+            }
+            Ok(download::download(&days))
+        }
+        "download" => {
+            let (year, day) = year_day(&args.finish()?)?;
+            Ok(download::download(&[(year, day)]))
+        }
+        "new" => {
+            let (year, day) = year_day(&args.finish()?)?;
+            Ok(scaffold::new_day(year, day))
+        }
+        "doctor" => no_arguments(args).map(|()| doctor::doctor()),
+        "verify" => no_arguments(args).map(|()| verify::verify()),
+        "readme" => no_arguments(args).map(|()| readme::readme()),
+        _ => Err(format!("unknown command {command}")),
+    }
+}
+
+/// Entry point of the per-day `dayN-<year>` binaries
+pub fn day_main(year: u16, day: u8) -> ExitCode {
+    worker::serve_if_requested();
+    let mut args = Args::from_env();
+    let parsed = run_options(&mut args).and_then(|mut options| {
+        match args.finish()?.as_slice() {
+            [] => {}
+            [input] => positional_input(&mut options, Some(input.clone()))?,
+            [_, extra, ..] => return Err(format!("unexpected argument {extra}")),
+        }
+        Ok(options)
+    });
+    match parsed {
+        Ok(options) => runner::run(year, day, &options),
+        Err(err) => usage(&err),
+    }
+}
+
+fn run_options(args: &mut Args) -> Result<RunOptions, String> {
+    let mut format = ReportFormat::from_env()?;
+    if let Some(unit) = args.option("--unit")? {
+        format.unit = unit.parse()?;
+    }
+    if let Some(precision) = args.option("--precision")? {
+        format.precision = precision.parse()?;
+    }
+    let output = match args.option("--output")? {
+        Some(output) => output.parse()?,
+        None => Output::default(),
+    };
+    let spans = args.flag("--spans");
+    let trace = args.option("--trace")?.map(PathBuf::from);
+    if output != Output::Text && (spans || trace.is_some()) {
+        return Err("--spans and --trace print text, they can't be combined with --output".to_string());
+    }
+    let (input, example) = input_options(args)?;
+    Ok(RunOptions {
+        input,
+        example,
+        spans,
+        trace,
+        format,
+        timeout: args
+            .option("--timeout")?
+            .map(|timeout| timeout.parse::<Reported>().map(|Reported(timeout)| timeout))
+            .transpose()
+            .map_err(|err| format!("--timeout: {err}"))?,
+        output,
+    })
+}
+
+/// Reads `--input <path>` and `--example <n>`, which pick the input in different ways
+fn input_options(args: &mut Args) -> Result<(Option<PathBuf>, Option<u8>), String> {
+    let input = args.option("--input")?.map(PathBuf::from);
+    let example = args.number("--example")?;
+    if input.is_some() && example.is_some() {
+        return Err("--input and --example can't be combined".to_string());
+    }
+    Ok((input, example))
+}
+
+/// Uses an input path given after the day, like `aoc run 2025 9 polygon.txt` or `-` for standard input
+fn positional_input(options: &mut RunOptions, input: Option<String>) -> Result<(), String> {
+    let Some(input) = input else {
+        return Ok(());
+    };
+    if options.input.is_some() || options.example.is_some() {
+        return Err(format!("{input} picks the input, it can't be combined with --input or --example"));
+    }
+    options.input = Some(PathBuf::from(input));
+    Ok(())
+}
+
+fn year_day(rest: &[String]) -> Result<(u16, u8), String> {
+    match rest {
+        [year, day] => match (year.parse(), day.parse()) {
+            (Ok(year), Ok(day)) => Ok((year, day)),
+            _ => Err("year and day must be numbers".to_string()),
+        },
+        _ => Err("expected <year> <day>".to_string()),
+    }
+}
+
+fn no_arguments(args: Args) -> Result<(), String> {
+    match args.finish()?.first() {
+        Some(arg) => Err(format!("unexpected argument {arg}")),
+        None => Ok(()),
+    }
+}
+
+fn usage(err: &str) -> ExitCode {
+    eprintln!("{err}\n{USAGE}");
+    ExitCode::FAILURE
+}
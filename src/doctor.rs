@@ -0,0 +1,433 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use utils::input::{self, InputLocator};
+
+use crate::registry::REGISTRY;
+
+/// An inconsistency between the places a day is declared, with how to fix it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    /// What the problem is about, e.g. `2025 day 6` or `.vscode/launch.json`
+    pub subject: String,
+    pub message: String,
+    pub fix: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}\n    fix: {}", self.subject, self.message, self.fix)
+    }
+}
+
+/// The parts of a `Cargo.toml` the checks look at
+#[derive(Debug, Default)]
+struct Manifest {
+    package: Option<String>,
+    /// Dependency names with their specification, e.g. `{ path = "utils" }`
+    dependencies: BTreeMap<String, String>,
+    /// `[[bin]]` targets as name and path
+    bins: Vec<(String, String)>,
+    /// Members of the workspace, possibly with `*` wildcards
+    members: Vec<String>,
+    /// Directories excluded from the workspace
+    exclude: Vec<String>,
+}
+
+impl Manifest {
+    /// Reads the keys the checks need, line by line, without understanding the rest of TOML
+    fn parse(text: &str) -> Self {
+        let mut manifest = Manifest::default();
+        let mut section = "";
+        for line in text.lines().map(str::trim) {
+            if line.starts_with('[') {
+                section = line;
+                if line == "[[bin]]" {
+                    manifest.bins.push(Default::default());
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let unquoted = value.trim_matches('"').to_string();
+            match (section, key) {
+                ("[package]", "name") => manifest.package = Some(unquoted),
+                ("[dependencies]", _) => {
+                    manifest.dependencies.insert(key.to_string(), value.to_string());
+                }
+                ("[[bin]]", "name") => manifest.bins.last_mut().unwrap().0 = unquoted,
+                ("[[bin]]", "path") => manifest.bins.last_mut().unwrap().1 = unquoted,
+                ("[workspace]", "members") => manifest.members = string_list(value),
+                ("[workspace]", "exclude") => manifest.exclude = string_list(value),
+                _ => {}
+            }
+        }
+        manifest
+    }
+}
+
+/// Reads a single-line array of strings, like `["utils", "2025"]`
+fn string_list(value: &str) -> Vec<String> {
+    value
+        .trim_matches(['[', ']'])
+        .split(',')
+        .map(|item| item.trim().trim_matches('"').to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Returns whether the relative `path` matches the workspace member `pattern`, where `*` matches within a path component
+fn is_member(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.trim_end_matches('/').split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    pattern.len() == path.len()
+        && pattern.iter().zip(&path).all(|(pattern, component)| match pattern.split_once('*') {
+            Some((prefix, suffix)) => {
+                component.len() >= prefix.len() + suffix.len()
+                    && component.starts_with(prefix)
+                    && component.ends_with(suffix)
+            }
+            None => pattern == component,
+        })
+}
+
+/// A day found on disk as `<year>/day<day>`
+struct Day {
+    year: u16,
+    day: u8,
+    dir: PathBuf,
+}
+
+impl Day {
+    fn subject(&self) -> String {
+        format!("{} day {}", self.year, self.day)
+    }
+
+    fn bin(&self) -> String {
+        format!("day{}-{}", self.day, self.year)
+    }
+}
+
+/// Cross-checks the days on disk under `root` against the root manifest,
+/// their own manifests, their inputs and tests, the launch configurations,
+/// and the `registered` solutions
+pub fn check(root: &Path, registered: &[(u16, u8)]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut problem = |subject: &str, message: String, fix: String| {
+        problems.push(Problem {
+            subject: subject.to_string(),
+            message,
+            fix,
+        })
+    };
+    let root_manifest = match std::fs::read_to_string(root.join("Cargo.toml")) {
+        Ok(text) => Manifest::parse(&text),
+        Err(err) => {
+            problem("Cargo.toml", format!("can't be read: {err}"), "run aoc doctor from a checkout".to_string());
+            return problems;
+        }
+    };
+    let launch = std::fs::read_to_string(root.join(".vscode").join("launch.json")).ok();
+    let days = find_days(root);
+    let locator = InputLocator::new(root);
+
+    for day in &days {
+        let subject = day.subject();
+        let scaffold = format!("run `aoc new {} {}`, which only adds what's missing", day.year, day.day);
+        let main = format!("{}/day{}/src/main.rs", day.year, day.day);
+        match root_manifest.bins.iter().find(|(name, _)| *name == day.bin()) {
+            None => problem(&subject, format!("has no [[bin]] {} in Cargo.toml", day.bin()), scaffold.clone()),
+            Some((_, path)) if *path != main => problem(
+                &subject,
+                format!("[[bin]] {} in Cargo.toml points at {path}", day.bin()),
+                format!("set its path to \"{main}\""),
+            ),
+            Some(_) => {}
+        }
+        if !root_manifest.exclude.contains(&day.year.to_string()) {
+            problem(
+                &subject,
+                format!("{} isn't excluded from the workspace, so its day manifests confuse cargo", day.year),
+                format!("add \"{}\" to exclude under [workspace] in Cargo.toml", day.year),
+            );
+        }
+        if !registered.contains(&(day.year, day.day)) {
+            problem(&subject, "isn't in the registry, so aoc can't run it".to_string(), scaffold.clone());
+        }
+        check_day_manifest(day, &root_manifest, &mut problem);
+        // Cargo only uses the lock file at the root of a workspace, so a member's own is stale
+        let relative = format!("{}/day{}", day.year, day.day);
+        if root_manifest.members.iter().any(|pattern| is_member(pattern, &relative))
+            && day.dir.join("Cargo.lock").exists()
+        {
+            problem(
+                &subject,
+                "has a Cargo.lock that cargo ignores, since the day is a member of the workspace".to_string(),
+                format!("delete {relative}/Cargo.lock"),
+            );
+        }
+        let candidates = locator.candidates(day.year, day.day);
+        if !candidates.iter().any(|path| input::is_saved(path)) {
+            let message = if candidates.iter().any(|path| path.is_file()) {
+                "has an empty input"
+            } else {
+                "has no input"
+            };
+            problem(
+                &subject,
+                message.to_string(),
+                format!(
+                    "save the puzzle input as {relative}/input, or run `aoc download {} {}`",
+                    day.year, day.day
+                ),
+            );
+        }
+        let lib = std::fs::read_to_string(day.dir.join("src").join("lib.rs")).unwrap_or_default();
+        if !lib.contains("#[test]") {
+            problem(
+                &subject,
+                "has no tests".to_string(),
+                "add #[test] functions checking the examples from the puzzle text".to_string(),
+            );
+        }
+        if let Some(launch) = &launch {
+            let configs = [
+                format!("\"--bin={}\"", day.bin()),
+                format!("\"y{}::day{}::\"", day.year, day.day),
+            ];
+            if !configs.iter().all(|config| launch.contains(config)) {
+                problem(
+                    &subject,
+                    "is missing its launch configurations in .vscode/launch.json".to_string(),
+                    scaffold.clone(),
+                );
+            }
+        }
+    }
+
+    for (name, path) in &root_manifest.bins {
+        if name.starts_with("day") && !root.join(path).is_file() {
+            problem(
+                "Cargo.toml",
+                format!("[[bin]] {name} points at {path}, which doesn't exist"),
+                format!("remove the [[bin]] {name} section"),
+            );
+        }
+    }
+    for &(year, day) in registered {
+        if !days.iter().any(|found| (found.year, found.day) == (year, day)) {
+            problem(
+                "src/registry.rs",
+                format!("registers {year} day {day}, which has no {year}/day{day} directory"),
+                format!("remove its entry, or run `aoc new {year} {day}`"),
+            );
+        }
+    }
+    if let Some(launch) = &launch {
+        for bin in launch.split("\"--bin=").skip(1).filter_map(|rest| rest.split('"').next()) {
+            if !root_manifest.bins.iter().any(|(name, _)| name == bin) {
+                problem(
+                    ".vscode/launch.json",
+                    format!("debugs {bin}, which isn't a binary in Cargo.toml"),
+                    format!("remove the configurations for {bin}"),
+                );
+            }
+        }
+    }
+    problems
+}
+
+/// Checks a day's own `Cargo.toml` declares the package and every crate its source uses
+fn check_day_manifest(day: &Day, root_manifest: &Manifest, problem: &mut impl FnMut(&str, String, String)) {
+    let subject = day.subject();
+    let path = format!("{}/day{}/Cargo.toml", day.year, day.day);
+    let Ok(text) = std::fs::read_to_string(day.dir.join("Cargo.toml")) else {
+        problem(&subject, format!("has no {path}"), format!("run `aoc new {} {}`", day.year, day.day));
+        return;
+    };
+    let manifest = Manifest::parse(&text);
+    let name = format!("day{}", day.day);
+    if manifest.package.as_ref() != Some(&name) {
+        problem(
+            &subject,
+            format!("{path} names the package {}", manifest.package.as_deref().unwrap_or("nothing")),
+            format!("set name = \"{name}\" under [package]"),
+        );
+    }
+    let root_package = root_manifest.package.clone().unwrap_or_default();
+    // The crates a day can use, with the dependency that provides them and how to declare it
+    let mut crates: Vec<(String, String, String)> = root_manifest
+        .dependencies
+        .iter()
+        .map(|(dependency, spec)| {
+            let spec = match spec.split_once("path = \"") {
+                Some((before, after)) => format!("{before}path = \"../../{after}"),
+                None => spec.clone(),
+            };
+            (dependency.replace('-', "_"), dependency.clone(), spec)
+        })
+        .collect();
+    crates.push(("aoc".to_string(), root_package, "{ path = \"../..\" }".to_string()));
+
+    let sources = source_files(&day.dir.join("src"));
+    for (krate, dependency, spec) in crates {
+        let user = sources.iter().find(|(_, source)| uses_crate(source, &krate));
+        if let Some((file, _)) = user
+            && !manifest.dependencies.contains_key(&dependency)
+        {
+            problem(
+                &subject,
+                format!("{path} doesn't depend on {dependency}, which src/{file} uses"),
+                format!("add `{dependency} = {spec}` under [dependencies]"),
+            );
+        }
+    }
+}
+
+/// Returns whether `source` refers to `krate` by a path like `krate::item`
+fn uses_crate(source: &str, krate: &str) -> bool {
+    let needle = format!("{krate}::");
+    source.match_indices(&needle).any(|(index, _)| {
+        !source[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    })
+}
+
+/// The `.rs` files directly in `dir`, by name, with their contents
+fn source_files(dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(String, String)> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "rs"))
+        .filter_map(|entry| {
+            let source = std::fs::read_to_string(entry.path()).ok()?;
+            Some((entry.file_name().to_string_lossy().into_owned(), source))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Days laid out as `<year>/day<day>` under `root`, in chronological order
+fn find_days(root: &Path) -> Vec<Day> {
+    let numbered = |dir: &Path, prefix: &str| -> Vec<(u32, PathBuf)> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let number = name.strip_prefix(prefix)?.parse().ok()?;
+                Some((number, entry.path()))
+            })
+            .collect()
+    };
+    let mut days: Vec<Day> = numbered(root, "")
+        .into_iter()
+        .filter(|(year, _)| (2015..=9999).contains(year))
+        .flat_map(|(year, year_dir)| {
+            numbered(&year_dir, "day")
+                .into_iter()
+                .filter(|(day, _)| (1..=25).contains(day))
+                .map(move |(day, dir)| Day {
+                    year: year as u16,
+                    day: day as u8,
+                    dir,
+                })
+        })
+        .collect();
+    days.sort_by_key(|day| (day.year, day.day));
+    days
+}
+
+/// Checks this repository and prints each problem found
+pub fn doctor() -> ExitCode {
+    let registered: Vec<(u16, u8)> = REGISTRY.iter().map(|entry| (entry.year, entry.day)).collect();
+    let problems = check(Path::new(env!("CARGO_MANIFEST_DIR")), &registered);
+    for problem in &problems {
+        println!("{problem}");
+    }
+    match problems.len() {
+        0 => {
+            println!("No problems found");
+            ExitCode::SUCCESS
+        }
+        1 => {
+            println!("\n1 problem found");
+            ExitCode::FAILURE
+        }
+        count => {
+            println!("\n{count} problems found");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_check() {
+    let tree = crate::fixture::TempTree::new(
+        "doctor",
+        &[
+            (
+                "Cargo.toml",
+                "[package]\nname = \"advent-of-code\"\n\n[workspace]\nmembers = [\"utils\", \"2025/day2\"]\nexclude = [\"2025\"]\n\n[dependencies]\nutils = { path = \"utils\" }\nregex = \"1\"\n\n[[bin]]\nname = \"day1-2025\"\npath = \"2025/day1/src/main.rs\"\n\n[[bin]]\nname = \"day3-2025\"\npath = \"2025/day3/src/main.rs\"\n\n[[bin]]\nname = \"day5-2025\"\npath = \"2025/day5/src/main.rs\"",
+            ),
+            (
+                "2025/day1/Cargo.toml",
+                "[package]\nname = \"day1\"\n\n[dependencies]\nutils = { path = \"../../utils\" }\nadvent-of-code = { path = \"../..\" }\n",
+            ),
+            ("2025/day1/src/main.rs", "fn main() { aoc::cli::day_main(2025, 1); }"),
+            ("2025/day1/src/lib.rs", "use utils::answer::Answer;\n#[test]\nfn test1() {}\n"),
+            ("2025/day1/input", "1\n"),
+            ("2025/day1/Cargo.lock", ""),
+            ("2025/day2/Cargo.toml", "[package]\nname = \"day2\"\n\n[dependencies]\n"),
+            ("2025/day2/Cargo.lock", ""),
+            ("2025/day2/src/main.rs", "fn main() { aoc::cli::day_main(2025, 2); }"),
+            ("2025/day2/src/lib.rs", "use regex::Regex;\nfn my_utils::x() {}\n"),
+            ("2025/day5/Cargo.toml", "[package]\nname = \"day5\"\n\n[dependencies]\nadvent-of-code = { path = \"../..\" }\n"),
+            ("2025/day5/src/main.rs", "fn main() { aoc::cli::day_main(2025, 5); }"),
+            ("2025/day5/src/lib.rs", "#[test]\nfn test1() {}\n"),
+            ("2025/day5/input", ""),
+            (
+                ".vscode/launch.json",
+                "{ \"args\": [\"--bin=day1-2025\"], \"args\": [\"y2025::day1::\"], \"args\": [\"--bin=day5-2025\"], \"args\": [\"y2025::day5::\"], \"args\": [\"--bin=day9-2025\"] }",
+            ),
+        ],
+    );
+    let problems = check(tree.path(), &[(2025, 1), (2025, 4), (2025, 5)]);
+    let found: Vec<(&str, &str)> = problems
+        .iter()
+        .map(|problem| (problem.subject.as_str(), problem.message.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("2025 day 2", "has no [[bin]] day2-2025 in Cargo.toml"),
+            ("2025 day 2", "isn't in the registry, so aoc can't run it"),
+            ("2025 day 2", "2025/day2/Cargo.toml doesn't depend on regex, which src/lib.rs uses"),
+            ("2025 day 2", "2025/day2/Cargo.toml doesn't depend on advent-of-code, which src/main.rs uses"),
+            ("2025 day 2", "has a Cargo.lock that cargo ignores, since the day is a member of the workspace"),
+            ("2025 day 2", "has no input"),
+            ("2025 day 2", "has no tests"),
+            ("2025 day 2", "is missing its launch configurations in .vscode/launch.json"),
+            ("2025 day 5", "has an empty input"),
+            ("Cargo.toml", "[[bin]] day3-2025 points at 2025/day3/src/main.rs, which doesn't exist"),
+            ("src/registry.rs", "registers 2025 day 4, which has no 2025/day4 directory"),
+            (".vscode/launch.json", "debugs day9-2025, which isn't a binary in Cargo.toml"),
+        ]
+    );
+    assert_eq!(problems[2].fix, "add `regex = \"1\"` under [dependencies]");
+    assert!(is_member("2025/day*", "2025/day12"));
+    assert!(is_member("2025/*/", "2025/day1"));
+    assert!(!is_member("2025/*", "2025/day1/src"));
+}
@@ -0,0 +1,470 @@
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use utils::input;
+
+use crate::runner;
+
+/// Site the inputs are downloaded from, unless configured otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the value of the site's `session` cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the site the inputs are downloaded from
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable naming the config file, `$XDG_CONFIG_HOME/aoc/config` if unset
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Environment variable naming the cache directory, `$XDG_CACHE_HOME` if unset
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+
+/// Shortest time between two requests, across every run of the client
+pub const THROTTLE: Duration = Duration::from_secs(3);
+
+/// How long a single download may take
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Settings of the download client
+///
+/// Read from a config file of `key = value` lines (`session`, `base_url`
+/// and `contact`), where `#` starts a comment. [`SESSION_VAR`] and
+/// [`BASE_URL_VAR`] take precedence over the file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged-in browser
+    pub session: Option<String>,
+    /// [`DEFAULT_BASE_URL`] if `None`
+    pub base_url: Option<String>,
+    /// How the site's operators can reach whoever runs the client, an email or a repository URL
+    ///
+    /// It goes in the User-Agent, which the site asks automated tools to set.
+    pub contact: Option<String>,
+}
+
+impl Config {
+    /// Reads the config file if there is one, then applies the environment
+    pub fn load() -> Result<Config, String> {
+        let mut config = match config_path() {
+            Some(path) => match std::fs::read_to_string(&path) {
+                Ok(text) => Config::parse(&text).map_err(|err| format!("{}: {err}", path.display()))?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Config::default(),
+                Err(err) => return Err(format!("failed to read {}: {err}", path.display())),
+            },
+            None => Config::default(),
+        };
+        for (var, setting) in [(SESSION_VAR, &mut config.session), (BASE_URL_VAR, &mut config.base_url)] {
+            if let Ok(value) = std::env::var(var)
+                && !value.trim().is_empty()
+            {
+                *setting = Some(value.trim().to_string());
+            }
+        }
+        Ok(config)
+    }
+
+    fn parse(text: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", index + 1));
+            };
+            let setting = match key.trim() {
+                "session" => &mut config.session,
+                "base_url" => &mut config.base_url,
+                "contact" => &mut config.contact,
+                key => return Err(format!("line {}: unknown setting {key}", index + 1)),
+            };
+            *setting = Some(value.trim().to_string());
+        }
+        Ok(config)
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(dir.join("aoc").join("config"))
+}
+
+/// File holding the time of the last request, shared by every run
+fn stamp_path() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_VAR) {
+        return Some(PathBuf::from(dir).join("last-request"));
+    }
+    let dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+    Some(dir.join("aoc").join("last-request"))
+}
+
+/// Where a day's input came from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already saved at this path, and wasn't downloaded again
+    Cached(PathBuf),
+    /// It was downloaded and saved at this path
+    Downloaded(PathBuf),
+}
+
+/// One request made by a [`Client`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request<'a> {
+    pub url: &'a str,
+    pub user_agent: &'a str,
+    /// Value of the `session` cookie
+    pub session: &'a str,
+}
+
+/// Makes the HTTP requests of a [`Client`]
+pub trait Transport: std::fmt::Debug {
+    /// Requests `request.url`, writes the body to `body` and returns the HTTP status code
+    ///
+    /// # Errors
+    /// If there's no response at all, e.g. because the site can't be reached
+    fn get(&self, request: &Request, body: &Path) -> Result<u16, String>;
+}
+
+/// Makes requests by running `curl`, which takes care of HTTPS
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Curl;
+
+impl Transport for Curl {
+    fn get(&self, request: &Request, body: &Path) -> Result<u16, String> {
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--max-time", &TIMEOUT.as_secs().to_string()])
+            .args(["--user-agent", request.user_agent])
+            // The cookie is passed on stdin so it doesn't show up in the process list
+            .args(["--header", "@-", "--write-out", "%{http_code}", "--output"])
+            .arg(body)
+            .arg(request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to run curl: {err}"))?;
+        let cookie = format!("Cookie: session={}\n", request.session);
+        let written = curl.stdin.take().expect("stdin is piped").write_all(cookie.as_bytes());
+        let output = curl.wait_with_output().map_err(|err| format!("failed to run curl: {err}"))?;
+        if !output.status.success() || written.is_err() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        let status = String::from_utf8_lossy(&output.stdout);
+        status
+            .trim()
+            .parse()
+            .map_err(|_| format!("curl reported no status code, but {status}"))
+    }
+}
+
+/// Downloads puzzle inputs, at most one request every [`THROTTLE`]
+///
+/// Requests are made through a [`Transport`], [`Curl`] by default. The time of
+/// the last request is kept in a stamp file when there is one (see
+/// [`Client::with_stamp`]), so the throttle also holds across runs.
+#[derive(Debug)]
+pub struct Client {
+    transport: Box<dyn Transport>,
+    base_url: String,
+    session: String,
+    user_agent: String,
+    interval: Duration,
+    stamp: Option<PathBuf>,
+    last_request: Option<SystemTime>,
+}
+
+impl Client {
+    /// Creates a client for the site at `base_url`, logged in with the `session` cookie
+    ///
+    /// `contact` is added to the User-Agent, so the site's operators can reach whoever runs it.
+    pub fn new(base_url: &str, session: &str, contact: &str) -> Self {
+        Self {
+            transport: Box::new(Curl),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: format!("{}/{} ({contact})", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            interval: THROTTLE,
+            stamp: None,
+            last_request: None,
+        }
+    }
+
+    /// Creates a client from `config`, which must have a session and a contact,
+    /// throttled through the stamp file in the cache directory
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let file = || config_path().map_or("the config file".to_string(), |path| path.display().to_string());
+        let session = config
+            .session
+            .as_deref()
+            .ok_or_else(|| format!("no session cookie: set {SESSION_VAR} or `session = <cookie>` in {}", file()))?;
+        let contact = config.contact.as_deref().ok_or_else(|| {
+            format!(
+                "no contact: set `contact = <email or repository URL>` in {}, the site asks automated tools to say who runs them",
+                file()
+            )
+        })?;
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        let client = Self::new(base_url, session, contact);
+        Ok(match stamp_path() {
+            Some(stamp) => client.with_stamp(stamp),
+            None => client,
+        })
+    }
+
+    /// Waits at least `interval` between requests instead of [`THROTTLE`]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Makes requests through `transport` instead of [`Curl`]
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Box::new(transport);
+        self
+    }
+
+    /// Keeps the time of the last request in `stamp`, so other clients using it wait their turn
+    pub fn with_stamp(mut self, stamp: PathBuf) -> Self {
+        self.stamp = Some(stamp);
+        self
+    }
+
+    /// Time of the last request made by this client or recorded in the stamp file
+    fn last_request(&self) -> Option<SystemTime> {
+        let stamped = self.stamp.as_ref().and_then(|stamp| {
+            let nanos = std::fs::read_to_string(stamp).ok()?.trim().parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+        });
+        self.last_request.max(stamped)
+    }
+
+    /// Records that a request is being made, failing if the stamp file can't be written
+    fn stamp_request(&mut self) -> Result<(), String> {
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        let Some(stamp) = &self.stamp else {
+            return Ok(());
+        };
+        let nanos = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+        stamp
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(stamp, format!("{nanos}\n")))
+            .map_err(|err| format!("failed to write {}: {err}", stamp.display()))
+    }
+
+    /// Address of the input of `year`/`day`
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    /// Makes sure one of `candidates` holds the input of `year`/`day`,
+    /// downloading it to the first one if none does
+    ///
+    /// Empty files are placeholders and don't count, see [`input::is_saved`].
+    pub fn fetch(&mut self, year: u16, day: u8, candidates: &[PathBuf]) -> Result<Fetched, String> {
+        if let Some(path) = candidates.iter().find(|path| input::is_saved(path)) {
+            return Ok(Fetched::Cached(path.clone()));
+        }
+        let path = candidates.first().ok_or("nowhere to save the input")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+        }
+        self.download(&self.url(year, day), path)?;
+        Ok(Fetched::Downloaded(path.clone()))
+    }
+
+    /// Downloads `url` to `path`, through a temporary file so an interrupted download leaves nothing behind
+    fn download(&mut self, url: &str, path: &Path) -> Result<(), String> {
+        if let Some(last) = self.last_request() {
+            // A stamp from the future (e.g. after the clock went back) waits the full interval
+            let elapsed = last.elapsed().unwrap_or_default();
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+        self.stamp_request()?;
+        let partial = path.with_extension("partial");
+        let request = Request {
+            url,
+            user_agent: &self.user_agent,
+            session: &self.session,
+        };
+        let status = self.transport.get(&request, &partial);
+        let body = std::fs::read(&partial).unwrap_or_default();
+        let status = match status {
+            Ok(status) => status,
+            Err(err) => {
+                let _ = std::fs::remove_file(&partial);
+                return Err(format!("failed to download {url}: {err}"));
+            }
+        };
+        if status != 200 {
+            let _ = std::fs::remove_file(&partial);
+            let reason = body.lines().next().and_then(Result::ok).unwrap_or_default();
+            let hint = match status {
+                400 | 500 => " (is the session cookie still valid?)",
+                404 => " (is the puzzle unlocked yet?)",
+                _ => "",
+            };
+            return Err(format!("{url} returned {status}{hint}: {}", reason.trim()));
+        }
+        std::fs::rename(&partial, path).map_err(|err| format!("failed to save {}: {err}", path.display()))
+    }
+}
+
+/// Downloads the inputs of `days` that aren't saved yet, and prints where each one is
+pub fn download(days: &[(u16, u8)]) -> ExitCode {
+    let mut client = match Config::load().and_then(|config| Client::from_config(&config)) {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut failed = false;
+    for &(year, day) in days {
+        match client.fetch(year, day, &runner::locator(None).candidates(year, day)) {
+            Ok(Fetched::Cached(path)) => println!("{year} day {day}: already saved in {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => println!("{year} day {day}: downloaded to {}", path.display()),
+            Err(err) => {
+                eprintln!("{year} day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+#[test]
+fn test_config() {
+    let config = Config::parse("# from the browser's cookies\nsession = cafe\n\ncontact = me@example.com # for the User-Agent\n");
+    assert_eq!(
+        config,
+        Ok(Config {
+            session: Some("cafe".to_string()),
+            base_url: None,
+            contact: Some("me@example.com".to_string()),
+        })
+    );
+    let err = Client::from_config(&Config { contact: None, ..config.unwrap() }).unwrap_err();
+    assert!(err.starts_with("no contact"), "{err}");
+    assert!(Config::parse("session cafe").is_err());
+    assert!(Config::parse("cookie = cafe").is_err());
+}
+
+#[test]
+fn test_fetch() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    /// A stand-in for the site, serving one input and 404 for everything else
+    #[derive(Clone, Debug, Default)]
+    struct Site {
+        /// URL, User-Agent and session of every request
+        requests: Arc<Mutex<Vec<[String; 3]>>>,
+    }
+
+    impl Transport for Site {
+        fn get(&self, request: &Request, body: &Path) -> Result<u16, String> {
+            self.requests.lock().unwrap().push([request.url, request.user_agent, request.session].map(String::from));
+            let (status, text) = match request.url.strip_prefix("http://site") {
+                Some("/2025/day/3/input") => (200, "1 2 3\n"),
+                Some(_) => (404, "404 Not Found\n"),
+                None => return Err("Couldn't resolve host".to_string()),
+            };
+            std::fs::write(body, text).map_err(|err| err.to_string())?;
+            Ok(status)
+        }
+    }
+
+    let tree = crate::fixture::TempTree::new("download", &[("repo/2025/day3/input", "")]);
+    let root = tree.path();
+    let saved = root.join("inputs/2025/day3/input");
+    let placeholder = root.join("repo/2025/day3/input");
+    let candidates = [saved.clone(), placeholder];
+    let interval = Duration::from_millis(100);
+    let stamp = root.join("cache/last-request");
+    let site = Site::default();
+    let new_client = |base_url: &str| {
+        Client::new(base_url, "cafe", "me@example.com")
+            .with_transport(site.clone())
+            .with_interval(interval)
+            .with_stamp(stamp.clone())
+    };
+    let mut client = new_client("http://site/");
+
+    let started = Instant::now();
+    assert_eq!(client.fetch(2025, 3, &candidates), Ok(Fetched::Downloaded(saved.clone())));
+    assert_eq!(std::fs::read_to_string(&saved).unwrap(), "1 2 3\n");
+    assert_eq!(client.fetch(2025, 3, &candidates), Ok(Fetched::Cached(saved.clone())));
+
+    // Another run, e.g. the next `aoc download`, still waits for the interval
+    let mut client = new_client("http://site");
+    let missing = root.join("inputs/2025/day4/input");
+    let err = client.fetch(2025, 4, std::slice::from_ref(&missing)).unwrap_err();
+    assert_eq!(err, "http://site/2025/day/4/input returned 404 (is the puzzle unlocked yet?): 404 Not Found");
+    assert!(started.elapsed() >= interval);
+    assert!(!missing.exists() && !missing.with_extension("partial").exists());
+
+    let err = new_client("http://elsewhere").fetch(2025, 4, std::slice::from_ref(&missing)).unwrap_err();
+    assert!(err.ends_with("Couldn't resolve host"), "{err}");
+    assert!(!missing.with_extension("partial").exists());
+
+    let requests = site.requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    let user_agent = format!("advent-of-code/{} (me@example.com)", env!("CARGO_PKG_VERSION"));
+    assert_eq!(requests[0], ["http://site/2025/day/3/input", &user_agent, "cafe"]);
+}
+
+#[test]
+#[ignore = "runs curl, which may be missing or sandboxed"]
+fn test_curl() {
+    use std::net::TcpListener;
+
+    // A stand-in for the site, answering one request with its headers as the body
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request: Vec<String> = std::io::BufReader::new(&stream)
+            .lines()
+            .map_while(Result::ok)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        let body = request.join("\n");
+        let response = format!(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+    });
+
+    let tree = crate::fixture::TempTree::new("curl", &[]);
+    let body = tree.path().join("body");
+    let url = format!("http://{address}/2025/day/3/input");
+    let request = Request {
+        url: &url,
+        user_agent: "advent-of-code/test (me@example.com)",
+        session: "cafe",
+    };
+    assert_eq!(Curl.get(&request, &body), Ok(404));
+    let headers = std::fs::read_to_string(&body).unwrap();
+    for header in [
+        "GET /2025/day/3/input HTTP/1.1",
+        "User-Agent: advent-of-code/test (me@example.com)",
+        "Cookie: session=cafe",
+    ] {
+        assert!(headers.lines().any(|line| line == header), "{header} not in {headers}");
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+/// A directory of files for a test, removed when dropped even if the test fails
+pub struct TempTree {
+    root: PathBuf,
+}
+
+impl TempTree {
+    /// Creates `aoc-<name>-<pid>` in the temporary directory, replacing any
+    /// leftover, with `files` given as relative paths and their contents
+    pub fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        for (path, contents) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        Self { root }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
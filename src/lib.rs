@@ -0,0 +1,21 @@
+pub mod cli;
+pub mod doctor;
+pub mod download;
+#[cfg(test)]
+mod fixture;
+pub mod output;
+pub mod readme;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod summary;
+pub mod verify;
+pub mod watch;
+pub mod worker;
+
+#[path = "../2025/mod.rs"]
+pub mod y2025;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: utils::alloc::CountingAllocator = utils::alloc::CountingAllocator;
@@ -0,0 +1,346 @@
+use std::io::Write;
+use std::str::FromStr;
+
+use utils::structs::trace::escape;
+
+use crate::runner::{Failure, PhaseResult, Solved};
+
+/// Header line of [`Output::Csv`]
+pub const CSV_HEADER: &str = "year,day,part,answer,duration_ns,status,error";
+
+/// How `aoc run` writes its results
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Output {
+    /// Lines meant for people, with times in the chosen [`ReportFormat`](utils::structs::stopwatch::ReportFormat)
+    #[default]
+    Text,
+    /// One JSON object per part and line
+    Json,
+    /// A header line, then one line per part
+    Csv,
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            "csv" => Ok(Output::Csv),
+            _ => Err(format!("output must be text, json or csv, got {s}")),
+        }
+    }
+}
+
+/// The result of one part, as written by [`Output::Json`] and [`Output::Csv`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` unless the part ran and solved the puzzle
+    pub answer: Option<String>,
+    /// Wall-clock time of the part alone, without parsing
+    pub duration_ns: u128,
+    /// `ok`, `unsolved`, `timeout`, `parse_error`, `panicked`, `skipped` or `no_input`
+    pub status: &'static str,
+    /// Set for `parse_error`, `panicked` and `no_input`
+    ///
+    /// Like a missing `answer`, `None` is written as `null` in JSON and as an empty field in CSV.
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// Records for both parts of a day
+    ///
+    /// When parsing failed, both parts carry the parse failure, since
+    /// that's why they didn't run.
+    pub fn from_solved(year: u16, day: u8, solved: &Solved) -> [Record; 2] {
+        std::array::from_fn(|index| {
+            let part: &PhaseResult = &solved.parts[index];
+            let failure = solved.parse.outcome.as_ref().err().or(part.outcome.as_ref().err());
+            let (answer, status, error) = match (&part.outcome, failure) {
+                (Ok(answer), _) if !answer.is_solved() => (None, "unsolved", None),
+                (Ok(answer), _) => (Some(answer.to_string()), "ok", None),
+                (Err(_), Some(failure)) | (Err(failure), None) => (None, status(failure), error(failure)),
+            };
+            Record {
+                year,
+                day,
+                part: index as u8 + 1,
+                answer,
+                duration_ns: part.duration.as_nanos(),
+                status,
+                error,
+            }
+        })
+    }
+
+    /// Records for both parts of a day whose input couldn't be found
+    pub fn no_input(year: u16, day: u8, error: &str) -> [Record; 2] {
+        [1, 2].map(|part| Record {
+            year,
+            day,
+            part,
+            answer: None,
+            duration_ns: 0,
+            status: "no_input",
+            error: Some(error.to_string()),
+        })
+    }
+
+    /// The record as one line of JSON, without the newline
+    pub fn json(&self) -> String {
+        let string = |value: &Option<String>| match value {
+            Some(value) => format!("\"{}\"", escape(value)),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"status\":\"{}\",\"error\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            string(&self.answer),
+            self.duration_ns,
+            self.status,
+            string(&self.error)
+        )
+    }
+
+    /// The record as one line of CSV, in the order of [`CSV_HEADER`], without the newline
+    pub fn csv(&self) -> String {
+        let field = |value: &Option<String>| match value {
+            Some(value) if value.contains([',', '"', '\n', '\r']) => format!("\"{}\"", value.replace('"', "\"\"")),
+            Some(value) => value.clone(),
+            None => String::new(),
+        };
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            field(&self.answer),
+            self.duration_ns,
+            self.status,
+            field(&self.error)
+        )
+    }
+}
+
+impl Record {
+    /// Reads back the records written by [`Output::Csv`]
+    ///
+    /// Anything before the [`CSV_HEADER`] line is skipped, and quoted
+    /// fields may span several lines.
+    pub fn read_csv(text: &str) -> Result<Vec<Record>, String> {
+        let mut offset = 0;
+        let mut rest = None;
+        for line in text.split_inclusive('\n') {
+            offset += line.len();
+            if line.trim_end_matches(['\n', '\r']) == CSV_HEADER {
+                rest = Some(&text[offset..]);
+                break;
+            }
+        }
+        let mut rest = rest.ok_or("no CSV header found")?;
+        let mut records = Vec::new();
+        while !rest.is_empty() {
+            let (fields, next) = csv_row(rest).map_err(|err| format!("record {}: {err}", records.len() + 1))?;
+            records.push(Record::from_fields(fields).map_err(|err| format!("record {}: {err}", records.len() + 1))?);
+            rest = next;
+        }
+        Ok(records)
+    }
+
+    fn from_fields(fields: Vec<String>) -> Result<Record, String> {
+        let [year, day, part, answer, duration_ns, status, error] =
+            <[String; 7]>::try_from(fields).map_err(|fields| format!("expected 7 fields, found {}", fields.len()))?;
+        let optional = |value: String| (!value.is_empty()).then_some(value);
+        Ok(Record {
+            year: year.parse().map_err(|_| format!("bad year {year:?}"))?,
+            day: day.parse().map_err(|_| format!("bad day {day:?}"))?,
+            part: part.parse().map_err(|_| format!("bad part {part:?}"))?,
+            answer: optional(answer),
+            duration_ns: duration_ns.parse().map_err(|_| format!("bad duration {duration_ns:?}"))?,
+            status: STATUSES
+                .into_iter()
+                .find(|known| *known == status)
+                .ok_or_else(|| format!("unknown status {status:?}"))?,
+            error: optional(error),
+        })
+    }
+}
+
+/// Splits the first row off `text`, returning its fields and the rows after it
+///
+/// Fields are quoted like [`Record::csv`] quotes them: in double quotes,
+/// with `""` for a quote, and line breaks kept as they are.
+fn csv_row(text: &str) -> Result<(Vec<String>, &str), String> {
+    let mut fields = Vec::new();
+    let mut rest = text;
+    loop {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let mut value = String::new();
+            let mut chars = quoted.char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((index, '"')) if quoted[index + 1..].starts_with('"') => {
+                        value.push('"');
+                        chars.next();
+                    }
+                    Some((index, '"')) => break index + 1,
+                    Some((_, c)) => value.push(c),
+                    None => return Err("unterminated quote".to_string()),
+                }
+            };
+            fields.push(value);
+            rest = &quoted[end..];
+        } else {
+            let end = rest.find([',', '\r', '\n']).unwrap_or(rest.len());
+            fields.push(rest[..end].to_string());
+            rest = &rest[end..];
+        }
+        if let Some(next) = rest.strip_prefix(',') {
+            rest = next;
+        } else if let Some(next) = rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n')) {
+            return Ok((fields, next));
+        } else if rest.is_empty() {
+            return Ok((fields, rest));
+        } else {
+            return Err("text after a quoted field".to_string());
+        }
+    }
+}
+
+/// Every status a [`Record`] can have
+const STATUSES: [&str; 7] = ["ok", "unsolved", "timeout", "parse_error", "panicked", "skipped", "no_input"];
+
+/// Machine-readable name of a failure, used as a record's status
+fn status(failure: &Failure) -> &'static str {
+    match failure {
+        Failure::Timeout => "timeout",
+        Failure::Parse(_) => "parse_error",
+        Failure::Panicked { .. } => "panicked",
+        Failure::Skipped => "skipped",
+    }
+}
+
+/// What went wrong, without repeating the status
+fn error(failure: &Failure) -> Option<String> {
+    match failure {
+        Failure::Parse(err) => Some(err.to_string()),
+        Failure::Panicked {
+            message,
+            location: Some(location),
+        } => Some(format!("{location}: {message}")),
+        Failure::Panicked {
+            message,
+            location: None,
+        } => Some(message.clone()),
+        Failure::Timeout | Failure::Skipped => None,
+    }
+}
+
+/// Writes `records` in `output`, one per line, with the CSV header first
+///
+/// # Panics
+/// If `output` is [`Output::Text`], which has no record format
+pub fn render(output: Output, records: &[Record]) -> String {
+    let lines: Vec<String> = match output {
+        Output::Text => panic!("text output isn't made of records"),
+        Output::Json => records.iter().map(Record::json).collect(),
+        Output::Csv => std::iter::once(CSV_HEADER.to_string())
+            .chain(records.iter().map(Record::csv))
+            .collect(),
+    };
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Keeps stdout for records, and sends anything else written to it to stderr from now on
+///
+/// Solutions may print while they run, and that would end up between the
+/// records. On Unix, this returns a
+/// duplicate of the original stdout and points the process's stdout at
+/// stderr. Elsewhere, stdout is returned as is, and solutions that print
+/// break the records.
+pub fn take_stdout() -> Box<dyn Write> {
+    #[cfg(unix)]
+    if let Some(records) = unix::take_stdout() {
+        return Box::new(records);
+    }
+    Box::new(std::io::stdout())
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::ffi::c_int;
+    use std::fs::File;
+    use std::io::Write;
+    use std::os::fd::{AsFd, AsRawFd};
+
+    unsafe extern "C" {
+        fn dup2(old: c_int, new: c_int) -> c_int;
+    }
+
+    /// Duplicates stdout, then replaces it with stderr
+    pub fn take_stdout() -> Option<File> {
+        let stdout = std::io::stdout();
+        stdout.lock().flush().ok()?;
+        let records = stdout.as_fd().try_clone_to_owned().ok()?;
+        // SAFETY: both descriptors are open, and `dup2` replaces the second atomically
+        let result = unsafe { dup2(std::io::stderr().as_raw_fd(), stdout.as_raw_fd()) };
+        (result >= 0).then(|| File::from(records))
+    }
+}
+
+#[test]
+fn test_records() {
+    let records = [
+        Record {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: Some("1036".to_string()),
+            duration_ns: 39_000,
+            status: "ok",
+            error: None,
+        },
+        Record {
+            year: 2025,
+            day: 2,
+            part: 1,
+            answer: None,
+            duration_ns: 12,
+            status: "parse_error",
+            error: Some("line 1, column 7: expected a number, found `x`".to_string()),
+        },
+    ];
+    assert_eq!(
+        render(Output::Json, &records),
+        "{\"year\":2025,\"day\":1,\"part\":1,\"answer\":\"1036\",\"duration_ns\":39000,\"status\":\"ok\",\"error\":null}\n\
+         {\"year\":2025,\"day\":2,\"part\":1,\"answer\":null,\"duration_ns\":12,\"status\":\"parse_error\",\"error\":\"line 1, column 7: expected a number, found `x`\"}\n"
+    );
+    assert_eq!(
+        render(Output::Csv, &records),
+        "year,day,part,answer,duration_ns,status,error\n\
+         2025,1,1,1036,39000,ok,\n\
+         2025,2,1,,12,parse_error,\"line 1, column 7: expected a number, found `x`\"\n"
+    );
+    assert_eq!(Record::read_csv(&render(Output::Csv, &records)).as_ref(), Ok(&records.to_vec()));
+    assert!(Record::read_csv(&format!("{CSV_HEADER}\n2025,1,1\n")).is_err());
+    assert!(Record::read_csv("2025,1,1,1036,39000,ok,\n").is_err());
+
+    // What a solution printed before the header is skipped, and errors may span lines
+    let panicked = Record {
+        year: 2025,
+        day: 3,
+        part: 2,
+        answer: None,
+        duration_ns: 5,
+        status: "panicked",
+        error: Some("src/lib.rs:9:5: assertion `left == right` failed\n  left: 1\n right: 2".to_string()),
+    };
+    let printed = format!("debug dump\n{CSV_HEADER} is coming\n{}", render(Output::Csv, std::slice::from_ref(&panicked)));
+    assert_eq!(Record::read_csv(&printed), Ok(vec![panicked]));
+}
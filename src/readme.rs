@@ -0,0 +1,204 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use utils::answers::Answers;
+use utils::structs::stopwatch::ReportDuration;
+
+use crate::registry::REGISTRY;
+use crate::runner;
+use crate::verify::answers_path;
+
+/// Marker comment preceding the generated tables in `README.md`
+pub const START_MARKER: &str = "<!-- day-records:start -->";
+/// Marker comment following the generated tables in `README.md`
+pub const END_MARKER: &str = "<!-- day-records:end -->";
+
+/// Text shown instead of an answer or timing for a part that isn't solved yet
+const UNSOLVED: &str = "unsolved";
+
+/// One line of the "Day Records" table
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    /// Formatted time taken to parse the input, `None` if it didn't parse
+    pub parse: Option<String>,
+    /// Answer and formatted timing of each part, `None` if unsolved
+    pub parts: [Option<(String, String)>; 2],
+}
+
+/// Renders one markdown table per year, with columns padded to line up
+pub fn render_tables(rows: &[Row]) -> String {
+    let mut years: Vec<u16> = rows.iter().map(|row| row.year).collect();
+    years.dedup();
+    let tables: Vec<String> = years
+        .iter()
+        .map(|&year| {
+            let lines: Vec<[String; 5]> = rows
+                .iter()
+                .filter(|row| row.year == year)
+                .map(|row| {
+                    let answer = |part: &Option<(String, String)>| match part {
+                        Some((answer, _)) => answer.clone(),
+                        None => UNSOLVED.to_string(),
+                    };
+                    let time = |part: &Option<(String, String)>| match part {
+                        Some((_, time)) => time.clone(),
+                        None => UNSOLVED.to_string(),
+                    };
+                    [
+                        row.day.to_string(),
+                        answer(&row.parts[0]),
+                        answer(&row.parts[1]),
+                        row.parse.clone().unwrap_or_else(|| "failed".to_string()),
+                        format!("{}/{}", time(&row.parts[0]), time(&row.parts[1])),
+                    ]
+                })
+                .collect();
+            format!("### {year}\n\n{}", render_table(&lines))
+        })
+        .collect();
+    tables.join("\n")
+}
+
+fn render_table(lines: &[[String; 5]]) -> String {
+    let header = ["Day", "Part 1", "Part 2", "Parse", "Time"].map(String::from);
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for line in lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let row = |cells: &[String; 5]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!(" {cell}{} ", " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("|{}|\n", cells.join("|"))
+    };
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(width + 2)).collect();
+    let mut table = row(&header);
+    table.push_str(&format!("|{}|\n", separator.join("|")));
+    for line in lines {
+        table.push_str(&row(line));
+    }
+    table
+}
+
+/// Replaces everything between [`START_MARKER`] and [`END_MARKER`] with `generated`
+pub fn replace_between_markers(readme: &str, generated: &str) -> Result<String, String> {
+    let start = readme
+        .find(START_MARKER)
+        .ok_or_else(|| format!("README.md has no {START_MARKER} marker"))?;
+    let end = readme[start..]
+        .find(END_MARKER)
+        .map(|end| start + end)
+        .ok_or_else(|| format!("README.md has no {END_MARKER} marker after {START_MARKER}"))?;
+    Ok(format!(
+        "{}{START_MARKER}\n{generated}{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+/// Runs every registered solution and rewrites the "Day Records" tables in `README.md`
+///
+/// A part counts as solved when its answer matches `answers.txt`. Parts
+/// without a recorded answer are shown as unsolved, and the README is left
+/// untouched if any part disagrees with its recorded answer.
+pub fn readme() -> ExitCode {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: timings come from a debug build, run with --release for real numbers");
+    }
+    let answers = match Answers::load(&answers_path()) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rows = Vec::new();
+    for entry in REGISTRY {
+        let input = match runner::locator(None).read(entry.year, entry.day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let solved = runner::solve(entry, &input, runner::DEFAULT_TIMEOUT);
+        let mut parts = [None, None];
+        for (index, result) in solved.parts.iter().enumerate() {
+            let answer = match (&result.outcome, &solved.parse.outcome) {
+                (Ok(answer), _) => answer.to_string(),
+                (Err(_), Err(failure)) => format!("{failure} while parsing"),
+                (Err(failure), Ok(())) => failure.to_string(),
+            };
+            match answers.get(entry.year, entry.day, index as u8 + 1) {
+                Some(expected) if result.outcome.as_ref().is_ok_and(|answer| answer.matches(expected)) => {
+                    parts[index] = Some((answer, result.duration.report()));
+                }
+                Some(expected) => {
+                    eprintln!(
+                        "{} day {} part {} answered {answer}, expected {expected}; run aoc verify",
+                        entry.year,
+                        entry.day,
+                        index + 1,
+                    );
+                    return ExitCode::FAILURE;
+                }
+                None => {}
+            }
+        }
+        rows.push(Row {
+            year: entry.year,
+            day: entry.day,
+            parse: solved.parse.outcome.is_ok().then(|| solved.parse.duration.report()),
+            parts,
+        });
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+    let updated = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))
+        .and_then(|readme| replace_between_markers(&readme, &render_tables(&rows)));
+    match updated.and_then(|readme| {
+        std::fs::write(&path, readme).map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }) {
+        Ok(()) => {
+            println!("Updated {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_replace_between_markers() {
+    let readme = format!("intro\n{START_MARKER}\nold table\n{END_MARKER}\noutro\n");
+    assert_eq!(
+        replace_between_markers(&readme, "new table\n").unwrap(),
+        format!("intro\n{START_MARKER}\nnew table\n{END_MARKER}\noutro\n")
+    );
+    assert!(replace_between_markers("no markers", "new table\n").is_err());
+}
+
+#[test]
+fn test_render_unsolved() {
+    let rows = [Row {
+        year: 2025,
+        day: 10,
+        parse: Some("3μs".to_string()),
+        parts: [Some(("7".to_string(), "12μs".to_string())), None],
+    }];
+    assert_eq!(
+        render_tables(&rows),
+        "### 2025\n\n\
+         | Day | Part 1 | Part 2   | Parse | Time          |\n\
+         |-----|--------|----------|-------|---------------|\n\
+         | 10  | 7      | unsolved | 3μs   | 12μs/unsolved |\n"
+    );
+}
@@ -0,0 +1,33 @@
+use utils::solution::AnySolution;
+
+use crate::y2025;
+
+/// A puzzle solution registered under its year and day
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn AnySolution,
+}
+
+/// Every solution the `aoc` binary can run, in chronological order
+pub static REGISTRY: &[Entry] = &[
+    Entry { year: 2025, day: 1, solution: &y2025::day1::Day1 },
+    Entry { year: 2025, day: 2, solution: &y2025::day2::Day2 },
+    Entry { year: 2025, day: 3, solution: &y2025::day3::Day3 },
+    Entry { year: 2025, day: 4, solution: &y2025::day4::Day4 },
+    Entry { year: 2025, day: 5, solution: &y2025::day5::Day5 },
+    Entry { year: 2025, day: 6, solution: &y2025::day6::Day6 },
+    Entry { year: 2025, day: 7, solution: &y2025::day7::Day7 },
+    Entry { year: 2025, day: 8, solution: &y2025::day8::Day8 },
+    Entry { year: 2025, day: 9, solution: &y2025::day9::Day9 },
+    Entry { year: 2025, day: 10, solution: &y2025::day10::Day10 },
+    Entry { year: 2025, day: 11, solution: &y2025::day11::Day11 },
+    Entry { year: 2025, day: 12, solution: &y2025::day12::Day12 },
+];
+
+/// Looks up the solution registered for `year` and `day`
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    REGISTRY
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
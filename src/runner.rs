@@ -0,0 +1,679 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::sync::Once;
+use std::sync::mpsc::{RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use utils::alloc::{AllocMeter, AllocStats};
+use utils::answer::Answer;
+use utils::baseline::{Baseline, Baselines, Comparison, Verdict, machine_fingerprint};
+use utils::bench::Bench;
+use utils::cancel::{CancellationToken, Cancelled};
+use utils::input::InputLocator;
+use utils::parse::ParseError;
+use utils::structs::stopwatch::clock::{Times, TimesWatch};
+use utils::structs::stopwatch::{ReportFormat, SpanRecord, SpanRecorder, SpanReport, span};
+use utils::structs::trace::chrome_trace;
+
+use crate::output::{self, Output, Record};
+use crate::registry::{self, Entry};
+use crate::worker::{self, Worker};
+
+/// Options controlling how a single day is run
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Explicit input file, or `-` for standard input, instead of searching for the day's input
+    pub input: Option<PathBuf>,
+    /// Run against the day's numbered example fixture instead of its input
+    pub example: Option<u8>,
+    /// Record timing spans and print them as a tree after the answers
+    pub spans: bool,
+    /// Write the recorded timing spans to this file as Chrome trace-event JSON
+    pub trace: Option<PathBuf>,
+    /// Unit and precision to print the time taken by each part in
+    pub format: ReportFormat,
+    /// How long each part may run, [`DEFAULT_TIMEOUT`] if `None`
+    pub timeout: Option<Duration>,
+    /// Print lines for people, or records for scripts
+    pub output: Output,
+}
+
+/// How long a part may run before it's cancelled
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a cancelled part gets to notice before its process is killed
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Options controlling how a single day is benchmarked
+#[derive(Clone, Debug, Default)]
+pub struct BenchOptions {
+    /// Explicit input file, or `-` for standard input, instead of searching for the day's input
+    pub input: Option<PathBuf>,
+    /// Benchmark the day's numbered example fixture instead of its input
+    pub example: Option<u8>,
+    pub bench: Bench,
+    /// Save the results as the baseline with this name
+    pub save: Option<String>,
+    /// Compare the results against the baseline with this name
+    pub compare: Option<String>,
+    /// Smallest relative change of the median that counts as a regression, as a fraction
+    pub threshold: f64,
+}
+
+/// Why a part has no answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The part was still running when its deadline passed
+    Timeout,
+    /// The input couldn't be parsed
+    Parse(ParseError),
+    /// The part, or parsing the input for it, panicked
+    Panicked {
+        message: String,
+        /// `file:line:column` of the panic, if known
+        location: Option<String>,
+    },
+    /// The part never ran, because parsing failed or an earlier part didn't stop after timing out
+    Skipped,
+}
+
+impl Failure {
+    /// Short name of the failure, without the details
+    pub fn status(&self) -> &'static str {
+        match self {
+            Failure::Timeout => "TIMEOUT",
+            Failure::Parse(_) => "PARSE ERROR",
+            Failure::Panicked { .. } => "PANICKED",
+            Failure::Skipped => "SKIPPED",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Timeout => write!(f, "TIMEOUT"),
+            Failure::Parse(err) => write!(f, "PARSE ERROR at {err}"),
+            Failure::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "PANICKED at {location}: {message}"),
+            Failure::Panicked {
+                message,
+                location: None,
+            } => write!(f, "PANICKED: {message}"),
+            Failure::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+thread_local! {
+    /// Set on worker threads, whose panics are reported as [`Failure::Panicked`] instead of printed
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    /// The last panic captured on this thread
+    static CAPTURED_PANIC: RefCell<Option<Failure>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that captures the panics of worker threads, leaving other threads to the previous hook
+pub fn capture_worker_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURE_PANICS.get() {
+                return previous(info);
+            }
+            let payload = info.payload();
+            let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(message), _) => message.to_string(),
+                (_, Some(message)) => message.clone(),
+                _ => "Box<dyn Any>".to_string(),
+            };
+            let location = info
+                .location()
+                .map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+            CAPTURED_PANIC.set(Some(Failure::Panicked { message, location }));
+        }));
+    });
+}
+
+/// The outcome and cost of one timed phase of a solution: parsing, or one of its parts
+pub struct PhaseResult<T = Answer> {
+    /// The part's answer, `()` for parsing, or why there isn't one
+    pub outcome: Result<T, Failure>,
+    /// Wall-clock time taken
+    pub duration: Duration,
+    /// Wall-clock time taken in TSC cycles, `None` without an invariant TSC
+    pub cycles: Option<u64>,
+    /// CPU time taken by the worker thread, or by the whole process for [`Solution::PARALLEL`](utils::solution::Solution::PARALLEL) solutions
+    ///
+    /// `None` on platforms without a CPU-time clock.
+    pub cpu: Option<Duration>,
+    /// Allocations made by the phase, `None` unless built with `count-allocations`
+    pub allocations: Option<AllocStats>,
+}
+
+impl<T> PhaseResult<T> {
+    /// A phase that has no answer because of `failure`, and has no CPU time or allocations to show
+    pub fn failed(failure: Failure, duration: Duration) -> Self {
+        Self {
+            outcome: Err(failure),
+            duration,
+            cycles: None,
+            cpu: None,
+            allocations: None,
+        }
+    }
+
+    /// Wall-clock, CPU time and cycles taken
+    pub fn times(&self) -> Times {
+        Times {
+            wall: self.duration,
+            cpu: self.cpu,
+            cycles: self.cycles,
+        }
+    }
+
+    /// Time taken, and allocations if they were counted, formatted for a result row
+    pub fn costs(&self, format: ReportFormat) -> String {
+        let times = self.times().report_as(format);
+        match self.allocations {
+            Some(allocations) => format!("{times}, {allocations}"),
+            None => times,
+        }
+    }
+
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> PhaseResult<U> {
+        PhaseResult {
+            outcome: self.outcome.map(f),
+            duration: self.duration,
+            cycles: self.cycles,
+            cpu: self.cpu,
+            allocations: self.allocations,
+        }
+    }
+}
+
+/// Results of parsing the input once and running both parts against it
+pub struct Solved {
+    pub parse: PhaseResult<()>,
+    pub parts: [PhaseResult; 2],
+}
+
+/// Runs `entry` against the raw puzzle input, parsing it and running each part under its own deadline
+///
+/// Parsing and the parts run one after the other in a [`Worker`], a
+/// child process, and the parsed input is shared by both parts. Each
+/// phase is timed on its own, so days that do their preprocessing in
+/// `parse` are measured the same way as days that don't.
+///
+/// A phase still running after `timeout` has its [`CancellationToken`]
+/// cancelled, and solutions that poll it stop and are reported as
+/// [`Failure::Timeout`]. A phase that doesn't stop within a grace period
+/// is reported the same way, and its process is killed, so it stops using
+/// a CPU. Any later phases are [`Failure::Skipped`].
+///
+/// A part that panics is reported as [`Failure::Panicked`] without
+/// affecting the other part, and so is a process that dies. If parsing
+/// fails or panics, both parts are skipped.
+pub fn solve(entry: &'static Entry, input: &str, timeout: Duration) -> Solved {
+    solve_in(worker::command(entry), entry, input, timeout)
+}
+
+/// Like [`solve`], with the worker started by `command`, which must end up in [`worker::serve`]
+fn solve_in(command: Command, entry: &Entry, input: &str, timeout: Duration) -> Solved {
+    let mut results = Vec::with_capacity(3);
+    match Worker::spawn(command, entry, input) {
+        Ok(mut worker) => {
+            for index in 0..3 {
+                let started = Instant::now();
+                let mut late = false;
+                let received = worker.results.recv_timeout(timeout).or_else(|err| match err {
+                    RecvTimeoutError::Timeout => {
+                        late = true;
+                        worker.cancel(index);
+                        worker.results.recv_timeout(GRACE_PERIOD)
+                    }
+                    disconnected => Err(disconnected),
+                });
+                match received {
+                    // A phase that finished after its deadline without noticing the cancellation still timed out
+                    Ok(result) if late => results.push(PhaseResult {
+                        outcome: Err(Failure::Timeout),
+                        ..result
+                    }),
+                    Ok(result) => results.push(result),
+                    Err(RecvTimeoutError::Timeout) => {
+                        worker.kill();
+                        results.push(PhaseResult::failed(Failure::Timeout, started.elapsed()));
+                        break;
+                    }
+                    // The worker stops early after a failed parse, and otherwise only if it died
+                    Err(RecvTimeoutError::Disconnected) => {
+                        if let Some(failure) = worker.crash() {
+                            results.push(PhaseResult::failed(failure, started.elapsed()));
+                        }
+                        break;
+                    }
+                }
+            }
+            worker.finish();
+        }
+        Err(err) => results.push(PhaseResult::failed(
+            Failure::Panicked {
+                message: err,
+                location: None,
+            },
+            Duration::ZERO,
+        )),
+    }
+    let mut results = results.into_iter();
+    let mut next = || {
+        results
+            .next()
+            .unwrap_or_else(|| PhaseResult::failed(Failure::Skipped, Duration::ZERO))
+    };
+    Solved {
+        parse: next().map(|_| ()),
+        parts: [next(), next()],
+    }
+}
+
+/// Body of the worker thread started by [`worker::serve`], sending the result of each phase as it finishes
+///
+/// Parsing has no answer, so its result is sent with [`Answer::Unsolved`],
+/// which [`solve`] drops.
+pub fn work(entry: &Entry, input: &str, tokens: &[CancellationToken; 3], sender: &Sender<PhaseResult>) {
+    CAPTURE_PANICS.set(true);
+    let _span = span(format!("{} day {}", entry.year, entry.day));
+    let mut meter = AllocMeter::start();
+    let mut watch = if entry.solution.parallel() {
+        TimesWatch::started_process_wide()
+    } else {
+        TimesWatch::started()
+    };
+    let mut phase = |token: &CancellationToken, name: &'static str, run: &mut dyn FnMut() -> Result<Answer, Failure>| {
+        let _installed = token.install();
+        let outcome = {
+            let _span = span(name);
+            panic::catch_unwind(AssertUnwindSafe(run))
+        };
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(payload) if payload.is::<Cancelled>() => Err(Failure::Timeout),
+            Err(_) => Err(CAPTURED_PANIC.take().unwrap_or(Failure::Panicked {
+                message: "unknown panic".to_string(),
+                location: None,
+            })),
+        };
+        let times = watch.lap();
+        PhaseResult {
+            outcome,
+            duration: times.wall,
+            cycles: times.cycles,
+            cpu: times.cpu,
+            allocations: meter.as_mut().map(AllocMeter::lap),
+        }
+    };
+
+    let mut parsed = None;
+    let parse = phase(&tokens[0], "parse", &mut || {
+        parsed = Some(entry.solution.parse(input).map_err(Failure::Parse)?);
+        Ok(Answer::Unsolved)
+    });
+    // Give up once the runner has stopped listening, or when there's no input to solve
+    let Some(parsed) = parsed else {
+        let _ = sender.send(parse);
+        return;
+    };
+    if sender.send(parse).is_err() {
+        return;
+    }
+    let part1 = phase(&tokens[1], "part 1", &mut || Ok(entry.solution.part1(&*parsed)));
+    if sender.send(part1).is_err() {
+        return;
+    }
+    let part2 = phase(&tokens[2], "part 2", &mut || Ok(entry.solution.part2(&*parsed)));
+    let _ = sender.send(part2);
+}
+
+/// Locates the input for `year`/`day` relative to this repository,
+/// unless an explicit path is given
+pub fn locator(input: Option<PathBuf>) -> InputLocator {
+    InputLocator::new(env!("CARGO_MANIFEST_DIR")).with_input(input)
+}
+
+/// Runs the solution registered for `year` and `day` against its input
+/// and prints both answers with their timings
+pub fn run(year: u16, day: u8, options: &RunOptions) -> ExitCode {
+    let Some(entry) = registry::find(year, day) else {
+        eprintln!("No solution registered for {year} day {day}");
+        return ExitCode::FAILURE;
+    };
+    let (path, input) = match locator(options.input.clone()).with_example(options.example).load(year, day) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut records_out = (options.output != Output::Text).then(output::take_stdout);
+    let recorder = (options.spans || options.trace.is_some()).then(SpanRecorder::start);
+    let solved = solve(entry, &input, options.timeout.unwrap_or(DEFAULT_TIMEOUT));
+    let records = recorder.map(SpanRecorder::finish_records);
+    let status = if solved.parse.outcome.is_ok() && solved.parts.iter().all(|result| result.outcome.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    };
+    if let Some(out) = &mut records_out {
+        if let Err(Failure::Parse(err)) = &solved.parse.outcome {
+            eprint!("{}", err.render(&path.display().to_string()));
+        }
+        let records = output::render(options.output, &Record::from_solved(year, day, &solved));
+        if let Err(err) = out.write_all(records.as_bytes()).and_then(|()| out.flush()) {
+            eprintln!("Failed to write the records: {err}");
+            return ExitCode::FAILURE;
+        }
+        return status;
+    }
+    match &solved.parse.outcome {
+        Ok(()) => println!("parse ({})", solved.parse.costs(options.format)),
+        Err(Failure::Timeout) => println!("parse {} after {}", Failure::Timeout, solved.parse.costs(options.format)),
+        Err(Failure::Parse(err)) => {
+            println!("parse {}", Failure::Parse(err.clone()));
+            eprint!("\n{}\n", err.render(&path.display().to_string()));
+        }
+        Err(failure) => println!("parse {failure}"),
+    }
+    for (part, result) in solved.parts.iter().enumerate() {
+        match &result.outcome {
+            Ok(answer) => println!("{}. {answer} ({})", part + 1, result.costs(options.format)),
+            Err(Failure::Timeout) => println!("{}. {} after {}", part + 1, Failure::Timeout, result.costs(options.format)),
+            Err(failure) => println!("{}. {failure}", part + 1),
+        }
+    }
+    match records {
+        Some(records) if !report_spans(&records, options.spans, options.trace.as_deref()) => ExitCode::FAILURE,
+        _ => status,
+    }
+}
+
+/// Prints the tree of recorded spans if `spans` is set, and writes them to `trace` if given
+///
+/// # Returns
+/// Whether the trace could be written
+pub fn report_spans(records: &[SpanRecord], spans: bool, trace: Option<&Path>) -> bool {
+    if spans {
+        print!("\n{}", SpanReport::from_records(records));
+    }
+    if let Some(path) = trace {
+        if let Err(err) = std::fs::write(path, chrome_trace(records)) {
+            eprintln!("Failed to write {}: {err}", path.display());
+            return false;
+        }
+        println!("Wrote trace to {}", path.display());
+    }
+    true
+}
+
+/// Benchmarks parsing and both parts of the solution registered for `year` and `day`
+///
+/// Fails if comparing against a baseline finds a phase that got
+/// significantly slower than the threshold allows.
+pub fn bench(year: u16, day: u8, options: &BenchOptions) -> ExitCode {
+    let Some(entry) = registry::find(year, day) else {
+        eprintln!("No solution registered for {year} day {day}");
+        return ExitCode::FAILURE;
+    };
+    let (path, input) = match locator(options.input.clone()).with_example(options.example).load(year, day) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = match entry.solution.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprint!("{}", err.render(&path.display().to_string()));
+            return ExitCode::FAILURE;
+        }
+    };
+    let previous = match &options.compare {
+        Some(name) => match Baselines::load(&baseline_path(name)) {
+            Ok(baselines) => match baselines.get(year, day) {
+                Some(baseline) => Some((name, baseline.clone())),
+                None => {
+                    eprintln!("Baseline {name} has no results for {year} day {day}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+    let machine = machine_fingerprint();
+    if let Some((name, baseline)) = &previous {
+        println!("Comparing against {name} from {}", baseline.revision);
+        if baseline.machine != machine {
+            eprintln!("warning: baseline {name} was recorded on {}", baseline.machine);
+        }
+    }
+
+    let bench = options.bench;
+    println!(
+        "{year} day {day}: {} iterations after {} warm-up",
+        bench.iterations, bench.warmup
+    );
+    let phases = [
+        ("parse", bench.run(|| entry.solution.parse(&input))),
+        ("part1", bench.run(|| entry.solution.part1(&*parsed))),
+        ("part2", bench.run(|| entry.solution.part2(&*parsed))),
+    ];
+    let mut regressed = false;
+    for (name, stats) in &phases {
+        println!("{name:<7} {stats}");
+        let Some(old) = previous.as_ref().and_then(|(_, baseline)| baseline.phase(name)) else {
+            continue;
+        };
+        let comparison = Comparison::new(old, stats, options.threshold);
+        println!("        {comparison}");
+        regressed |= comparison.verdict == Verdict::Slower;
+    }
+
+    if let Some(name) = &options.save {
+        let baseline = Baseline {
+            revision: git_revision(),
+            machine,
+            phases: phases.map(|(name, stats)| (name.to_string(), stats)).to_vec(),
+        };
+        if let Err(err) = save_baseline(name, year, day, baseline) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline {name}");
+    }
+    if regressed {
+        eprintln!(
+            "{year} day {day} got slower than the {:.1}% threshold",
+            options.threshold * 100.0
+        );
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Path of the baseline file called `name`
+///
+/// Baselines live in `bench-baselines`, which git ignores, so they survive
+/// `cargo clean` and checking out other revisions to compare against.
+pub fn baseline_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("bench-baselines")
+        .join(format!("{name}.txt"))
+}
+
+/// Adds or replaces the results of one day in the baseline file called `name`
+fn save_baseline(name: &str, year: u16, day: u8, baseline: Baseline) -> Result<(), String> {
+    let path = baseline_path(name);
+    let mut baselines = if path.exists() {
+        Baselines::load(&path).map_err(|err| err.to_string())?
+    } else {
+        Baselines::default()
+    };
+    baselines.insert(year, day, baseline);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+    }
+    std::fs::write(&path, baselines.to_string())
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+/// Short hash of the checked out commit, marked `-dirty` when there are local changes
+fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(env!("CARGO_MANIFEST_DIR"))
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let Some(revision) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain"]) {
+        Some(status) if !status.is_empty() => format!("{revision}-dirty"),
+        _ => revision,
+    }
+}
+
+/// Solves `entry` in a copy of the test binary that only runs `test`, which must start by serving `entry`
+#[cfg(test)]
+fn solve_in_test(test: &str, entry: &Entry, input: &str, timeout: Duration, envs: &[(&str, &Path)]) -> Solved {
+    let mut command = Command::new(std::env::current_exe().unwrap());
+    command.args([test, "--exact"]).env(worker::WORKER_VAR, "test");
+    command.envs(envs.iter().copied());
+    solve_in(command, entry, input, timeout)
+}
+
+#[test]
+fn test_timeouts() {
+    use utils::solution::Solution;
+
+    /// File the worker writes its process id to, so the test can check that it's gone
+    const PID_VAR: &str = "AOC_TEST_PID_FILE";
+
+    /// Part 1 polls for cancellation, part 2 ignores it and would spin forever
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn parse(&self, _input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &()) -> u8 {
+            loop {
+                utils::cancel::checkpoint();
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(&self, _input: &()) -> u8 {
+            if let Some(path) = std::env::var_os(PID_VAR) {
+                std::fs::write(path, std::process::id().to_string()).unwrap();
+            }
+            loop {
+                std::hint::spin_loop();
+            }
+        }
+    }
+
+    static SLOW: Entry = Entry {
+        year: 2000,
+        day: 1,
+        solution: &Slow,
+    };
+    if std::env::var_os(worker::WORKER_VAR).is_some() {
+        worker::serve(&SLOW);
+    }
+    let tree = crate::fixture::TempTree::new("timeouts", &[]);
+    let pid_file = tree.path().join("pid");
+    let timeout = Duration::from_millis(200);
+    let Solved {
+        parse,
+        parts: [part1, part2],
+    } = solve_in_test("runner::test_timeouts", &SLOW, "", timeout, &[(PID_VAR, &pid_file)]);
+    assert_eq!(parse.outcome, Ok(()));
+    assert_eq!(part1.outcome, Err(Failure::Timeout));
+    assert!(part1.duration >= timeout);
+    assert_eq!(part2.outcome, Err(Failure::Timeout));
+    assert!(part2.duration >= GRACE_PERIOD);
+
+    // The spinning worker was killed and reaped, so it no longer uses a CPU
+    let pid = std::fs::read_to_string(&pid_file).unwrap();
+    assert_ne!(pid.parse::<u32>().unwrap(), std::process::id());
+    #[cfg(target_os = "linux")]
+    assert!(!Path::new("/proc").join(&pid).exists(), "worker {pid} is still running");
+}
+
+#[test]
+fn test_panics() {
+    use utils::solution::Solution;
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = u8;
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn parse(&self, input: &str) -> Result<u8, ParseError> {
+            Ok(input.parse().unwrap())
+        }
+
+        fn part1(&self, _input: &u8) -> u8 {
+            panic!("Unknown operator")
+        }
+
+        fn part2(&self, input: &u8) -> u8 {
+            *input
+        }
+    }
+
+    static BROKEN: Entry = Entry {
+        year: 2000,
+        day: 2,
+        solution: &Broken,
+    };
+    if std::env::var_os(worker::WORKER_VAR).is_some() {
+        worker::serve(&BROKEN);
+    }
+    let solve = |input| solve_in_test("runner::test_panics", &BROKEN, input, DEFAULT_TIMEOUT, &[]);
+    let [part1, part2] = solve("7").parts;
+    let Err(Failure::Panicked { message, location }) = part1.outcome else {
+        panic!("part 1 should have panicked");
+    };
+    assert_eq!(message, "Unknown operator");
+    assert!(location.is_some_and(|location| location.starts_with("src/runner.rs:")));
+    assert_eq!(part2.outcome, Ok(Answer::from(7)));
+
+    let solved = solve("x");
+    assert!(matches!(solved.parse.outcome, Err(Failure::Panicked { .. })));
+    assert!(solved.parts.iter().all(|part| part.outcome == Err(Failure::Skipped)));
+}
@@ -0,0 +1,348 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Templates for a new day's files, with `{{year}}` and `{{day}}` placeholders
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.tmpl");
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+
+/// What scaffolding did to one file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Created,
+    Updated,
+    /// The file already had everything the day needs
+    Unchanged,
+    /// The file already existed and was left alone rather than overwritten
+    Kept,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Action::Created => "created",
+            Action::Updated => "updated",
+            Action::Unchanged => "unchanged",
+            Action::Kept => "kept",
+        })
+    }
+}
+
+/// Generates a day's source from the templates and registers it everywhere the repository lists days
+///
+/// Every step only adds what's missing, so running it again for the same
+/// day changes nothing, and source files that already exist are never
+/// overwritten.
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    /// Scaffolds days in the repository checked out at `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Creates and registers `year`/`day`
+    ///
+    /// # Returns
+    /// Each file touched, relative to the root, with what was done to it
+    pub fn create(&self, year: u16, day: u8) -> Result<Vec<(PathBuf, Action)>, String> {
+        if !(2015..=9999).contains(&year) || !(1..=25).contains(&day) {
+            return Err(format!("no puzzle for {year} day {day}"));
+        }
+        let render = |template: &str| {
+            template
+                .replace("{{year}}", &year.to_string())
+                .replace("{{day}}", &day.to_string())
+        };
+        let day_dir = Path::new(&year.to_string()).join(format!("day{day}"));
+        let mut actions = vec![
+            self.create_file(&day_dir.join("src").join("lib.rs"), &render(LIB_TEMPLATE))?,
+            self.create_file(&day_dir.join("src").join("main.rs"), &render(MAIN_TEMPLATE))?,
+            self.create_file(&day_dir.join("Cargo.toml"), &render(MANIFEST_TEMPLATE))?,
+        ];
+        actions.push(self.update("Cargo.toml", |manifest| register_bin(manifest, year, day))?);
+        actions.push(self.update(
+            &format!("{year}/mod.rs"),
+            |modules| Ok(register_module(modules, day)),
+        )?);
+        actions.push(self.update("src/lib.rs", |lib| Ok(register_year(lib, year)))?);
+        actions.push(self.update("src/registry.rs", |registry| register_entry(registry, year, day))?);
+        let launch = Path::new(".vscode").join("launch.json");
+        if self.root.join(&launch).exists() {
+            actions.push(self.update(&launch.to_string_lossy(), |launch| {
+                register_launch_configs(launch, year, day)
+            })?);
+        }
+        Ok(actions)
+    }
+
+    /// Writes `contents` to `path` unless it already exists
+    fn create_file(&self, path: &Path, contents: &str) -> Result<(PathBuf, Action), String> {
+        let full = self.root.join(path);
+        if full.exists() {
+            return Ok((path.to_path_buf(), Action::Kept));
+        }
+        if let Some(dir) = full.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+        }
+        std::fs::write(&full, contents)
+            .map_err(|err| format!("failed to write {}: {err}", full.display()))?;
+        Ok((path.to_path_buf(), Action::Created))
+    }
+
+    /// Rewrites `path` with `edit`, creating it from an empty file if it doesn't exist
+    fn update(
+        &self,
+        path: &str,
+        edit: impl FnOnce(&str) -> Result<String, String>,
+    ) -> Result<(PathBuf, Action), String> {
+        let full = self.root.join(path);
+        let existing = match std::fs::read_to_string(&full) {
+            Ok(contents) => Some(contents),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(format!("failed to read {}: {err}", full.display())),
+        };
+        let before = existing.as_deref().unwrap_or_default();
+        let after = edit(before).map_err(|err| format!("{}: {err}", full.display()))?;
+        let action = match existing {
+            Some(_) if after == before => return Ok((PathBuf::from(path), Action::Unchanged)),
+            Some(_) => Action::Updated,
+            None => Action::Created,
+        };
+        std::fs::write(&full, after)
+            .map_err(|err| format!("failed to write {}: {err}", full.display()))?;
+        Ok((PathBuf::from(path), action))
+    }
+}
+
+/// Adds the `dayN-<year>` binary to the root manifest, and the year to the workspace's excluded directories
+fn register_bin(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut manifest = manifest.to_string();
+    let exclude = "exclude = [";
+    let start = manifest
+        .find(exclude)
+        .ok_or("missing the workspace's exclude list")?
+        + exclude.len();
+    let end = start + manifest[start..].find(']').ok_or("unterminated exclude list")?;
+    let quoted = format!("\"{year}\"");
+    let excluded: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|dir| !dir.is_empty())
+        .collect();
+    if !excluded.contains(&quoted.as_str()) {
+        let excluded = [excluded, vec![quoted.as_str()]].concat().join(", ");
+        manifest.replace_range(start..end, &excluded);
+    }
+
+    let name = format!("name = \"day{day}-{year}\"");
+    if !manifest.lines().any(|line| line.trim() == name) {
+        let manifest_end = manifest.trim_end().len();
+        manifest.truncate(manifest_end);
+        manifest.push_str(&format!("\n\n[[bin]]\n{name}\npath = \"{year}/day{day}/src/main.rs\""));
+    }
+    Ok(manifest)
+}
+
+/// Adds `dayN` to a year's `mod.rs`
+fn register_module(modules: &str, day: u8) -> String {
+    let declaration = format!("pub mod day{day};");
+    if modules.lines().any(|line| line.trim() == declaration) {
+        return modules.to_string();
+    }
+    let mut modules = modules.to_string();
+    if !modules.is_empty() && !modules.ends_with('\n') {
+        modules.push('\n');
+    }
+    modules.push_str(&format!("#[path = \"day{day}/src/lib.rs\"]\n{declaration}\n"));
+    modules
+}
+
+/// Includes a year's `mod.rs` in the `aoc` library as `y<year>`
+fn register_year(lib: &str, year: u16) -> String {
+    let declaration = format!("pub mod y{year};");
+    if lib.lines().any(|line| line.trim() == declaration) {
+        return lib.to_string();
+    }
+    let addition = format!("#[path = \"../{year}/mod.rs\"]\n{declaration}\n");
+    // After the last year, or the other modules if there are no years yet
+    let last_year = lib
+        .match_indices("pub mod y")
+        .last()
+        .map(|(index, _)| index)
+        .or_else(|| lib.rfind("pub mod "));
+    let mut lib = lib.to_string();
+    match last_year {
+        Some(index) => {
+            let line_end = lib[index..].find('\n').map_or(lib.len(), |end| index + end + 1);
+            lib.insert_str(line_end, &format!("\n{addition}"));
+        }
+        None => lib.push_str(&addition),
+    }
+    lib
+}
+
+/// Adds the day's [`Entry`](crate::registry::Entry) to `REGISTRY`, keeping it in chronological order
+fn register_entry(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut registry = registry.to_string();
+    let import = format!("use crate::y{year};");
+    if !registry.lines().any(|line| line.trim() == import) {
+        let after = registry
+            .match_indices("use crate::")
+            .last()
+            .map(|(index, _)| index)
+            .ok_or("missing the imports of the year modules")?;
+        let line_end = after + registry[after..].find('\n').ok_or("unterminated import")? + 1;
+        registry.insert_str(line_end, &format!("{import}\n"));
+    }
+
+    let entry = format!("    Entry {{ year: {year}, day: {day}, solution: &y{year}::day{day}::Day{day} }},\n");
+    if registry.contains(entry.trim()) {
+        return Ok(registry);
+    }
+    let start = registry
+        .find("pub static REGISTRY")
+        .ok_or("missing REGISTRY")?;
+    let end = start + registry[start..].find("\n];").ok_or("unterminated REGISTRY")? + 1;
+    // Before the first entry that comes later, or at the end
+    let mut insert_at = end;
+    let mut offset = start;
+    for line in registry[start..end].split_inclusive('\n') {
+        let numbers: Vec<u16> = line
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|number| number.parse().ok())
+            .collect();
+        if line.trim_start().starts_with("Entry {")
+            && let [entry_year, entry_day, ..] = numbers[..]
+            && (entry_year, entry_day) > (year, u16::from(day))
+        {
+            insert_at = offset;
+            break;
+        }
+        offset += line.len();
+    }
+    registry.insert_str(insert_at, &entry);
+    Ok(registry)
+}
+
+/// Adds configurations to debug the day's binary and its tests to `.vscode/launch.json`
+fn register_launch_configs(launch: &str, year: u16, day: u8) -> Result<String, String> {
+    let bin = format!("day{day}-{year}");
+    if launch.contains(&format!("\"--bin={bin}\"")) {
+        return Ok(launch.to_string());
+    }
+    let configs = format!(
+        r#"        {{
+            "name": "Debug executable '{bin}'",
+            "type": "lldb",
+            "request": "launch",
+            "cargo": {{
+                "args": [
+                    "run",
+                    "--bin={bin}"
+                ]
+            }},
+            "args": []
+        }},
+        {{
+            "name": "Debug unit tests in executable '{bin}'",
+            "type": "lldb",
+            "request": "launch",
+            "cargo": {{
+                "args": [
+                    "test",
+                    "--lib"
+                ]
+            }},
+            "args": [
+                "y{year}::day{day}::"
+            ]
+        }}"#
+    );
+    let start = launch
+        .find("\"configurations\"")
+        .ok_or("missing \"configurations\"")?;
+    let end = start + launch[start..].find("\n    ]").ok_or("unterminated \"configurations\"")?;
+    let mut launch = launch.to_string();
+    let previous = launch[..end].trim_end();
+    if previous.ends_with('[') {
+        launch.insert_str(end, &format!("\n{configs}"));
+    } else {
+        let previous_end = previous.len();
+        launch.insert_str(previous_end, &format!(",\n{configs}"));
+    }
+    Ok(launch)
+}
+
+/// Scaffolds `year`/`day` in this repository and prints what changed
+pub fn new_day(year: u16, day: u8) -> ExitCode {
+    let scaffold = Scaffold::new(env!("CARGO_MANIFEST_DIR"));
+    let actions = match scaffold.create(year, day) {
+        Ok(actions) => actions,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for (path, action) in &actions {
+        println!("{action:<9} {}", path.display());
+    }
+    if actions.iter().any(|(_, action)| *action == Action::Kept) {
+        println!("\nExisting files were kept; delete them first to start over from the template.");
+    }
+    ExitCode::SUCCESS
+}
+
+#[test]
+fn test_scaffold() {
+    let tree = crate::fixture::TempTree::new(
+        "scaffold",
+        &[
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"utils\"]\nexclude = [\"2025\"]\n\n[[bin]]\nname = \"day1-2025\"\npath = \"2025/day1/src/main.rs\"",
+            ),
+            ("2025/mod.rs", "#[path = \"day1/src/lib.rs\"]\npub mod day1;\n"),
+            ("src/lib.rs", "pub mod cli;\n\n#[path = \"../2025/mod.rs\"]\npub mod y2025;\n"),
+            (
+                "src/registry.rs",
+                "use crate::y2025;\n\npub static REGISTRY: &[Entry] = &[\n    Entry { year: 2025, day: 1, solution: &y2025::day1::Day1 },\n];\n",
+            ),
+            ("2025/day1/src/lib.rs", "// solved\n"),
+            (".vscode/launch.json", "{\n    \"configurations\": [\n    ]\n}"),
+        ],
+    );
+    let root = tree.path();
+    let scaffold = Scaffold::new(root);
+    let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+
+    scaffold.create(2026, 1).unwrap();
+    scaffold.create(2025, 2).unwrap();
+    let actions = scaffold.create(2025, 1).unwrap();
+    assert_eq!(actions[0], (PathBuf::from("2025/day1/src/lib.rs"), Action::Kept));
+    assert_eq!(read("2025/day1/src/lib.rs"), "// solved\n");
+    assert!(read("2025/day2/src/lib.rs").contains("impl Solution for Day2 {"));
+    assert!(read("2026/day1/src/main.rs").contains("day_main(2026, 1)"));
+    assert!(read("Cargo.toml").contains("exclude = [\"2025\", \"2026\"]"));
+    assert!(read("Cargo.toml").ends_with("name = \"day2-2025\"\npath = \"2025/day2/src/main.rs\""));
+    assert_eq!(read("2026/mod.rs"), "#[path = \"day1/src/lib.rs\"]\npub mod day1;\n");
+    assert_eq!(
+        read("src/lib.rs"),
+        "pub mod cli;\n\n#[path = \"../2025/mod.rs\"]\npub mod y2025;\n\n#[path = \"../2026/mod.rs\"]\npub mod y2026;\n"
+    );
+    assert_eq!(
+        read("src/registry.rs"),
+        "use crate::y2025;\nuse crate::y2026;\n\npub static REGISTRY: &[Entry] = &[\n    Entry { year: 2025, day: 1, solution: &y2025::day1::Day1 },\n    Entry { year: 2025, day: 2, solution: &y2025::day2::Day2 },\n    Entry { year: 2026, day: 1, solution: &y2026::day1::Day1 },\n];\n"
+    );
+
+    let launch = read(".vscode/launch.json");
+    assert!(launch.contains("        },\n        {\n            \"name\": \"Debug executable 'day2-2025'\""));
+    assert!(launch.ends_with("                \"y2025::day1::\"\n            ]\n        }\n    ]\n}"));
+
+    let again = scaffold.create(2026, 1).unwrap();
+    assert!(again.iter().all(|(_, action)| matches!(action, Action::Kept | Action::Unchanged)));
+}
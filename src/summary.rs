@@ -0,0 +1,306 @@
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use utils::structs::stopwatch::{ReportDuration, ReportFormat, SpanRecorder};
+
+use crate::output::{self, Output, Record};
+use crate::registry::{Entry, REGISTRY};
+use crate::runner::{self, Failure, PhaseResult, Solved};
+
+/// Number of phases listed after the table by `aoc run --all`
+const SLOWEST: usize = 5;
+
+/// Options controlling `aoc run --all`
+#[derive(Clone, Debug)]
+pub struct AllOptions {
+    /// Only run days of this year
+    pub year: Option<u16>,
+    /// Only run these days of each year
+    pub days: RangeInclusive<u8>,
+    /// Number of days run at the same time
+    pub jobs: usize,
+    /// Unit and precision to print times in
+    pub format: ReportFormat,
+    /// How long each phase may run, [`runner::DEFAULT_TIMEOUT`] if `None`
+    pub timeout: Option<Duration>,
+    /// Print a table for people, or records for scripts
+    pub output: Output,
+    /// Record timing spans and print them as a tree after the table
+    pub spans: bool,
+    /// Write the recorded timing spans to this file as Chrome trace-event JSON, one track per day
+    pub trace: Option<PathBuf>,
+}
+
+impl Default for AllOptions {
+    fn default() -> Self {
+        Self {
+            year: None,
+            days: 1..=25,
+            jobs: thread::available_parallelism().map_or(1, usize::from),
+            format: ReportFormat::default(),
+            timeout: None,
+            output: Output::default(),
+            spans: false,
+            trace: None,
+        }
+    }
+}
+
+/// Parses a `--days` value, either a single day or an inclusive range like `3-7`
+pub fn parse_days(days: &str) -> Result<RangeInclusive<u8>, String> {
+    let invalid = || format!("--days expects a day or a range like 3-7, got {days}");
+    let (start, end) = days.split_once('-').unwrap_or((days, days));
+    let start: u8 = start.trim().parse().map_err(|_| invalid())?;
+    let end: u8 = end.trim().parse().map_err(|_| invalid())?;
+    if start == 0 || start > end {
+        return Err(invalid());
+    }
+    Ok(start..=end)
+}
+
+/// Runs every registered solution matching the filters on a pool of workers,
+/// then prints one table with every answer and time, and the slowest phases
+///
+/// Allocations aren't shown, to keep the table to times; `aoc run` shows
+/// them for a single day. Each day is solved in its own process, and its
+/// spans come back on its own thread, so a trace gets one track per day.
+pub fn run_all(options: &AllOptions) -> ExitCode {
+    let entries: Vec<&'static Entry> = REGISTRY
+        .iter()
+        .filter(|entry| options.year.is_none_or(|year| entry.year == year))
+        .filter(|entry| options.days.contains(&entry.day))
+        .collect();
+    if entries.is_empty() {
+        eprintln!("No registered solutions match");
+        return ExitCode::FAILURE;
+    }
+    let records_out = (options.output != Output::Text).then(output::take_stdout);
+    let recorder = (options.spans || options.trace.is_some()).then(SpanRecorder::start);
+    let started = Instant::now();
+    let results = solve_all(&entries, options);
+    let elapsed = started.elapsed();
+    let spans = recorder.map(SpanRecorder::finish_records);
+    if let Some(out) = records_out {
+        return print_records(&entries, &results, options.output, out);
+    }
+
+    let time = |duration: Duration| duration.report_as(options.format);
+    let mut lines = Vec::new();
+    let mut totals = [Duration::ZERO; 4];
+    let mut failures = Vec::new();
+    for (entry, result) in entries.iter().zip(&results) {
+        let label = format!("{} day {}", entry.year, entry.day);
+        let solved = match result {
+            Ok(solved) => solved,
+            Err(err) => {
+                failures.push(format!("{label}: {err}"));
+                lines.push([
+                    entry.year.to_string(),
+                    entry.day.to_string(),
+                    "NO INPUT".to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]);
+                continue;
+            }
+        };
+        let phases = [
+            solved.parse.duration,
+            solved.parts[0].duration,
+            solved.parts[1].duration,
+        ];
+        let total: Duration = phases.iter().sum();
+        for (sum, duration) in totals.iter_mut().zip(phases.iter().chain([&total])) {
+            *sum += *duration;
+        }
+        if let Err(failure) = &solved.parse.outcome {
+            failures.push(format!("{label} parse: {failure}"));
+        }
+        for (index, part) in solved.parts.iter().enumerate() {
+            if let Err(failure) = &part.outcome
+                && *failure != Failure::Skipped
+            {
+                failures.push(format!("{label} part {}: {failure}", index + 1));
+            }
+        }
+        let answer = |part: &PhaseResult| match &part.outcome {
+            Ok(answer) => answer.to_string(),
+            Err(failure) => failure.status().to_string(),
+        };
+        lines.push([
+            entry.year.to_string(),
+            entry.day.to_string(),
+            answer(&solved.parts[0]),
+            answer(&solved.parts[1]),
+            phase_time(&solved.parse, options.format),
+            phase_time(&solved.parts[0], options.format),
+            phase_time(&solved.parts[1], options.format),
+            time(total),
+        ]);
+    }
+    lines.push([
+        "total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        time(totals[0]),
+        time(totals[1]),
+        time(totals[2]),
+        time(totals[3]),
+    ]);
+    print!("{}", render_table(&lines));
+    let workers = options.jobs.min(entries.len());
+    println!(
+        "\n{} days in {} on {workers} {}",
+        entries.len(),
+        time(elapsed),
+        if workers == 1 { "worker" } else { "workers" }
+    );
+
+    let mut phases: Vec<(String, Duration)> = entries
+        .iter()
+        .zip(&results)
+        .filter_map(|(entry, result)| Some((entry, result.as_ref().ok()?)))
+        .flat_map(|(entry, solved)| {
+            let label = |phase: &str| format!("{} day {} {phase}", entry.year, entry.day);
+            [
+                (label("parse"), solved.parse.duration),
+                (label("part 1"), solved.parts[0].duration),
+                (label("part 2"), solved.parts[1].duration),
+            ]
+        })
+        .collect();
+    phases.sort_by_key(|(_, duration)| std::cmp::Reverse(*duration));
+    println!("\nSlowest:");
+    for (label, duration) in phases.iter().take(SLOWEST) {
+        println!("  {:>10}  {label}", time(*duration));
+    }
+
+    let mut succeeded = failures.is_empty();
+    if !failures.is_empty() {
+        println!("\nFailures:");
+        for failure in &failures {
+            println!("  {failure}");
+        }
+    }
+    if let Some(spans) = spans {
+        succeeded &= runner::report_spans(&spans, options.spans, options.trace.as_deref());
+    }
+    if succeeded { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+/// Writes one record per part of every day to `out`, failing if any day didn't run cleanly
+fn print_records(
+    entries: &[&'static Entry],
+    results: &[Result<Solved, String>],
+    output: Output,
+    mut out: Box<dyn Write>,
+) -> ExitCode {
+    let mut succeeded = true;
+    let records: Vec<Record> = entries
+        .iter()
+        .zip(results)
+        .flat_map(|(entry, result)| match result {
+            Ok(solved) => {
+                succeeded &= solved.parse.outcome.is_ok() && solved.parts.iter().all(|part| part.outcome.is_ok());
+                Record::from_solved(entry.year, entry.day, solved)
+            }
+            Err(err) => {
+                succeeded = false;
+                Record::no_input(entry.year, entry.day, err)
+            }
+        })
+        .collect();
+    let records = output::render(output, &records);
+    if let Err(err) = out.write_all(records.as_bytes()).and_then(|()| out.flush()) {
+        eprintln!("Failed to write the records: {err}");
+        return ExitCode::FAILURE;
+    }
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Time taken by a phase, or the status of a phase that failed
+fn phase_time<T>(result: &PhaseResult<T>, format: ReportFormat) -> String {
+    match &result.outcome {
+        Ok(_) => result.times().report_wall_as(format),
+        Err(failure) => failure.status().to_string(),
+    }
+}
+
+/// Solves each of `entries` on `options.jobs` worker threads, returning the results in the same order
+fn solve_all(entries: &[&'static Entry], options: &AllOptions) -> Vec<Result<Solved, String>> {
+    let timeout = options.timeout.unwrap_or(runner::DEFAULT_TIMEOUT);
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..entries.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.clamp(1, entries.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&entry) = entries.get(index) else {
+                        break;
+                    };
+                    let result = runner::locator(None)
+                        .read(entry.year, entry.day)
+                        .map(|input| runner::solve(entry, &input, timeout))
+                        .map_err(|err| err.to_string());
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every entry is solved"))
+        .collect()
+}
+
+/// Lays out the rows of `aoc run --all` under a header, with times right-aligned
+fn render_table(lines: &[[String; 8]]) -> String {
+    let header = ["year", "day", "part 1", "part 2", "parse", "part 1", "part 2", "total"].map(String::from);
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for line in lines {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let row = |cells: &[String; 8]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if column == 1 || column >= 4 {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+    std::iter::once(&header).chain(lines).map(row).collect()
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(parse_days("3-7"), Ok(3..=7));
+    assert_eq!(parse_days("12"), Ok(12..=12));
+    assert!(parse_days("7-3").is_err());
+    assert!(parse_days("0").is_err());
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use utils::answers::Answers;
+
+use crate::registry::REGISTRY;
+use crate::runner;
+
+/// Path of the expected-answers file at the root of the repository
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+/// Runs every registered solution against its real input and compares
+/// each answer with the one recorded in `answers.txt`
+///
+/// Fails if any part disagrees with its expected answer or can't be run.
+/// Parts without a recorded answer are reported as missing, or as
+/// unsolved if they return [`Answer::Unsolved`](utils::answer::Answer::Unsolved).
+pub fn verify() -> ExitCode {
+    let answers = match Answers::load(&answers_path()) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (mut passed, mut failed, mut missing, mut unsolved) = (0, 0, 0, 0);
+    for entry in REGISTRY {
+        let label = format!("{} day {:>2}", entry.year, entry.day);
+        let input = match runner::locator(None).read(entry.year, entry.day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("FAIL     {label}         {err}");
+                failed += 2;
+                continue;
+            }
+        };
+        let solved = runner::solve(entry, &input, runner::DEFAULT_TIMEOUT);
+        if let Err(failure) = &solved.parse.outcome {
+            println!("FAIL     {label} parse   {failure}");
+            failed += 2;
+            continue;
+        }
+        for (index, result) in solved.parts.iter().enumerate() {
+            let part = index as u8 + 1;
+            let answer = match &result.outcome {
+                Ok(answer) => answer,
+                Err(failure) => {
+                    failed += 1;
+                    println!("FAIL     {label} part {part}  {failure}");
+                    continue;
+                }
+            };
+            match answers.get(entry.year, entry.day, part) {
+                Some(expected) if answer.matches(expected) => {
+                    passed += 1;
+                    println!("PASS     {label} part {part}  {answer}");
+                }
+                Some(expected) => {
+                    failed += 1;
+                    println!("FAIL     {label} part {part}  expected {expected}, got {answer}");
+                }
+                None if !answer.is_solved() => {
+                    unsolved += 1;
+                    println!("UNSOLVED {label} part {part}");
+                }
+                None => {
+                    missing += 1;
+                    println!("MISSING  {label} part {part}  got {answer}");
+                }
+            }
+        }
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} missing, {unsolved} unsolved");
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use utils::structs::stopwatch::ReportDuration;
+
+use crate::output::Record;
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How long the files must stay unchanged before a rebuild starts, so a burst of saves triggers one run
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Modification time and size of every watched file
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Rebuilds and re-runs `year`/`day` whenever its source or input changes
+///
+/// Each round builds the day's binary, runs its tests, then runs it
+/// against its real input and compares the answers and timings with the
+/// previous round. Build and test failures are reported and the watch
+/// carries on, waiting for the next change. Runs until interrupted.
+pub fn watch(year: u16, day: u8) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let day_dir = root.join(year.to_string()).join(format!("day{day}"));
+    if !day_dir.is_dir() {
+        eprintln!("{} doesn't exist; create it with aoc new {year} {day}", day_dir.display());
+        return ExitCode::FAILURE;
+    }
+    let watched = [day_dir.join("src"), day_dir.join("input")];
+    println!("Watching {} and {}", watched[0].display(), watched[1].display());
+
+    let mut previous: Option<Vec<Record>> = None;
+    let mut snapshot = take_snapshot(&watched);
+    loop {
+        if let Some(records) = round(root, year, day) {
+            print!("{}", compare(previous.as_deref(), &records));
+            previous = Some(records);
+        }
+        println!("\nWaiting for changes...");
+        snapshot = wait_for_change(&watched, snapshot);
+    }
+}
+
+/// Builds, tests and runs the day once
+///
+/// # Returns
+/// The records of the run, `None` if the build or the run failed
+fn round(root: &Path, year: u16, day: u8) -> Option<Vec<Record>> {
+    let bin = format!("day{day}-{year}");
+    let profile: &[&str] = if cfg!(debug_assertions) { &[] } else { &["--release"] };
+    let cargo = |args: &[&str]| {
+        let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        command.current_dir(root).args(args).args(profile);
+        command
+    };
+
+    println!("\n== building {bin}");
+    match cargo(&["build", "--quiet", "--bin", &bin]).status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("== build failed");
+            return None;
+        }
+        Err(err) => {
+            eprintln!("failed to run cargo: {err}");
+            return None;
+        }
+    }
+
+    println!("== testing y{year}::day{day}");
+    let filter = format!("y{year}::day{day}::");
+    match cargo(&["test", "--quiet", "--lib", "--", &filter]).status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => println!("== tests failed"),
+        Err(err) => eprintln!("failed to run cargo: {err}"),
+    }
+
+    println!("== running {bin}");
+    let output = cargo(&["run", "--quiet", "--bin", &bin, "--", "--output", "csv"])
+        .stderr(Stdio::inherit())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("failed to run cargo: {err}");
+            return None;
+        }
+    };
+    match Record::read_csv(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(records) if !records.is_empty() => Some(records),
+        Ok(_) => {
+            println!("== {bin} printed no results");
+            None
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            None
+        }
+    }
+}
+
+/// Describes each part's result, next to the previous round's when there is one
+fn compare(previous: Option<&[Record]>, records: &[Record]) -> String {
+    let mut lines = String::new();
+    for record in records {
+        let before = previous
+            .into_iter()
+            .flatten()
+            .find(|before| before.part == record.part);
+        let shown = |record: &Record| {
+            record
+                .answer
+                .clone()
+                .unwrap_or_else(|| record.status.to_uppercase().replace('_', " "))
+        };
+        let mut line = format!("{}. {}", record.part, shown(record));
+        if let Some(before) = before
+            && (before.answer != record.answer || before.status != record.status)
+        {
+            line.push_str(&format!(" (was {})", shown(before)));
+        }
+        let duration = Duration::from_nanos(record.duration_ns as u64);
+        line.push_str(&format!(" in {}", duration.report()));
+        if let Some(before) = before
+            && before.duration_ns > 0
+        {
+            let change = (record.duration_ns as f64 / before.duration_ns as f64 - 1.0) * 100.0;
+            let was = Duration::from_nanos(before.duration_ns as u64);
+            line.push_str(&format!(" ({change:+.1}% from {})", was.report()));
+        }
+        if let Some(error) = &record.error {
+            line.push_str(&format!("\n   {error}"));
+        }
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+    lines
+}
+
+/// Polls until the watched files change, then until they stop changing for [`DEBOUNCE`]
+///
+/// # Returns
+/// The snapshot the files settled on
+fn wait_for_change(watched: &[PathBuf], snapshot: Snapshot) -> Snapshot {
+    let mut current = snapshot.clone();
+    while current == snapshot {
+        thread::sleep(POLL_INTERVAL);
+        current = take_snapshot(watched);
+    }
+    loop {
+        thread::sleep(DEBOUNCE);
+        let settled = take_snapshot(watched);
+        if settled == current {
+            return settled;
+        }
+        current = settled;
+    }
+}
+
+/// Records the modification time and size of every file in or under `paths`
+fn take_snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending: Vec<PathBuf> = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path, (modified, metadata.len()));
+        }
+    }
+    snapshot
+}
+
+#[test]
+fn test_compare() {
+    let record = |part, answer: Option<&str>, duration_ns, status| Record {
+        year: 2025,
+        day: 10,
+        part,
+        answer: answer.map(String::from),
+        duration_ns,
+        status,
+        error: None,
+    };
+    let first = [record(1, None, 2_000, "unsolved"), record(2, Some("7"), 10_000, "ok")];
+    assert_eq!(compare(None, &first), "1. UNSOLVED in 2μs\n2. 7 in 10μs\n");
+    let second = [record(1, Some("455"), 3_000, "ok"), record(2, Some("7"), 5_000, "ok")];
+    assert_eq!(
+        compare(Some(&first), &second),
+        "1. 455 (was UNSOLVED) in 3μs (+50.0% from 2μs)\n2. 7 in 5μs (-50.0% from 10μs)\n"
+    );
+}
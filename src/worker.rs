@@ -0,0 +1,426 @@
+use std::borrow::Cow;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::panic;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use utils::alloc::AllocStats;
+use utils::answer::Answer;
+use utils::cancel::CancellationToken;
+use utils::parse::ParseError;
+use utils::structs::stopwatch::{self, SpanRecord, SpanRecorder};
+
+use crate::output;
+use crate::registry::{self, Entry};
+use crate::runner::{self, Failure, PhaseResult};
+
+/// Environment variable that makes a process serve one day's solution to its parent, set to `<year>-<day>`
+pub const WORKER_VAR: &str = "AOC_WORKER";
+
+/// A day's solution running in a child process, which can be killed when it ignores its cancellation
+///
+/// The child is a copy of the current executable started with
+/// [`WORKER_VAR`] set, which makes it call [`serve`]. It reads the input
+/// from stdin and writes one line per finished phase to stdout, which a
+/// reader thread named after the day turns back into [`PhaseResult`]s.
+/// Spans the child records are added to the parent's [`SpanRecorder`] as
+/// if they ran on that thread, so each day keeps its own track in a trace.
+pub struct Worker {
+    process: Child,
+    stdin: Option<ChildStdin>,
+    /// Result of each phase, in order, as the child finishes it
+    pub results: Receiver<PhaseResult>,
+    reader: JoinHandle<()>,
+}
+
+impl Worker {
+    /// Starts `command`, which must end up in [`serve`], and sends it `input`
+    pub fn spawn(mut command: Command, entry: &Entry, input: &str) -> Result<Self, String> {
+        let mut process = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| format!("failed to start the solution process: {err}"))?;
+        let stdout = process.stdout.take().expect("stdout is piped");
+        let (sender, results) = mpsc::channel();
+        let reader = thread::Builder::new()
+            .name(format!("{} day {}", entry.year, entry.day))
+            .spawn(move || {
+                let mut origin = Instant::now();
+                let mut spans = Vec::new();
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    let mut fields = line.split('\t');
+                    match fields.next() {
+                        Some("ready") => origin = Instant::now(),
+                        Some("phase") => {
+                            let result = decode_phase(fields).unwrap_or_else(|| {
+                                PhaseResult::failed(
+                                    Failure::Panicked {
+                                        message: format!("unreadable result from the solution process: {line}"),
+                                        location: None,
+                                    },
+                                    Duration::ZERO,
+                                )
+                            });
+                            let _ = sender.send(result);
+                        }
+                        Some("span") => spans.extend(decode_span(fields, origin)),
+                        // Anything else was printed by something other than `serve`, e.g. a test harness
+                        _ => {}
+                    }
+                }
+                stopwatch::import_spans(spans);
+            })
+            .expect("failed to spawn a reader thread");
+        let mut worker = Self {
+            process,
+            stdin: None,
+            results,
+            reader,
+        };
+        let mut stdin = worker.process.stdin.take().expect("stdin is piped");
+        let header = format!("{} {}\n", input.len(), u8::from(stopwatch::is_recording()));
+        if let Err(err) = stdin.write_all(header.as_bytes()).and_then(|()| stdin.write_all(input.as_bytes())) {
+            worker.kill();
+            worker.finish();
+            return Err(format!("failed to send the input to the solution process: {err}"));
+        }
+        worker.stdin = Some(stdin);
+        Ok(worker)
+    }
+
+    /// Asks the child to cancel phase `index`, 0 being parsing
+    pub fn cancel(&mut self, index: usize) {
+        if let Some(stdin) = &mut self.stdin {
+            let _ = writeln!(stdin, "cancel {index}").and_then(|()| stdin.flush());
+        }
+    }
+
+    /// Kills the child, which frees its CPU at once
+    pub fn kill(&mut self) {
+        let _ = self.process.kill();
+    }
+
+    /// Waits for the child to exit, and describes how it failed if it didn't exit cleanly
+    pub fn crash(&mut self) -> Option<Failure> {
+        match self.process.wait() {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(Failure::Panicked {
+                message: format!("the solution process stopped with {status}"),
+                location: None,
+            }),
+            Err(err) => Some(Failure::Panicked {
+                message: format!("lost the solution process: {err}"),
+                location: None,
+            }),
+        }
+    }
+
+    /// Waits for the child to exit and for its spans to be collected
+    pub fn finish(mut self) {
+        let _ = self.process.wait();
+        drop(self.stdin.take());
+        let _ = self.reader.join();
+    }
+}
+
+/// Command that runs `entry` in a copy of the current executable
+pub fn command(entry: &Entry) -> Command {
+    // An empty path fails to start, which `Worker::spawn` reports
+    let mut command = Command::new(std::env::current_exe().unwrap_or_default());
+    command.env(WORKER_VAR, format!("{}-{}", entry.year, entry.day));
+    command
+}
+
+/// Serves the day named by [`WORKER_VAR`] if it's set, and never returns then
+///
+/// Called first thing by every binary that solves days, so [`command`] can
+/// start any of them as a worker.
+pub fn serve_if_requested() {
+    let Ok(day) = std::env::var(WORKER_VAR) else {
+        return;
+    };
+    let entry = day
+        .split_once('-')
+        .and_then(|(year, day)| registry::find(year.parse().ok()?, day.parse().ok()?));
+    match entry {
+        Some(entry) => serve(entry),
+        None => {
+            eprintln!("{WORKER_VAR}={day} doesn't name a registered solution");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Runs `entry` for a parent [`Worker`], then exits
+///
+/// Reads the input from stdin, runs the phases on a worker thread and
+/// writes each result to stdout as it finishes. Later lines on stdin name
+/// phases to cancel, and stdin closing means the parent is gone, which
+/// makes the process exit. Anything the solution prints goes to stderr.
+pub fn serve(entry: &'static Entry) -> ! {
+    let mut out = output::take_stdout();
+    let mut stdin = BufReader::new(std::io::stdin());
+    let mut header = String::new();
+    let header = stdin.read_line(&mut header).ok().map(|_| header);
+    let Some((length, spans)) = header.as_deref().and_then(|header| header.trim().split_once(' ')) else {
+        eprintln!("expected `<input length> <spans>` on stdin");
+        std::process::exit(2);
+    };
+    let (Ok(length), spans) = (length.parse::<u64>(), spans == "1") else {
+        eprintln!("bad input length {length}");
+        std::process::exit(2);
+    };
+    let mut input = String::new();
+    if let Err(err) = (&mut stdin).take(length).read_to_string(&mut input) {
+        eprintln!("failed to read the input: {err}");
+        std::process::exit(2);
+    }
+
+    let tokens = [CancellationToken::new(), CancellationToken::new(), CancellationToken::new()];
+    {
+        let tokens = tokens.clone();
+        thread::spawn(move || {
+            for line in stdin.lines().map_while(Result::ok) {
+                let index = line.strip_prefix("cancel ").and_then(|index| index.parse::<usize>().ok());
+                if let Some(token) = index.and_then(|index| tokens.get(index)) {
+                    token.cancel();
+                }
+            }
+            std::process::exit(1);
+        });
+    }
+
+    runner::capture_worker_panics();
+    let recorder = spans.then(SpanRecorder::start);
+    let origin = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::Builder::new()
+        .name(format!("{} day {}", entry.year, entry.day))
+        .spawn(move || runner::work(entry, &input, &tokens, &sender))
+        .expect("failed to spawn a worker thread");
+    let mut send = |line: &str| {
+        if writeln!(out, "{line}").and_then(|()| out.flush()).is_err() {
+            std::process::exit(1);
+        }
+    };
+    send("ready");
+    // Each phase is sent as soon as it finishes, so the parent can tell which one is late
+    for result in receiver {
+        send(&encode_phase(&result));
+    }
+    if let Err(payload) = worker.join() {
+        panic::resume_unwind(payload);
+    }
+    if let Some(recorder) = recorder {
+        for record in recorder.finish_records() {
+            send(&encode_span(&record, origin));
+        }
+    }
+    std::process::exit(0);
+}
+
+/// Escapes tabs, newlines and backslashes, so a field fits on one tab-separated line
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverses [`escape`]
+fn unescape(field: &str) -> String {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// One `phase` line: duration, cycles, CPU time, allocations, then the outcome
+fn encode_phase(result: &PhaseResult) -> String {
+    let cycles = result.cycles.map_or("-".to_string(), |cycles| cycles.to_string());
+    let cpu = result.cpu.map_or("-".to_string(), |cpu| cpu.as_nanos().to_string());
+    let allocations = result.allocations.map_or("-".to_string(), |stats| {
+        format!("{},{},{}", stats.allocations, stats.bytes, stats.peak_bytes)
+    });
+    let outcome = match &result.outcome {
+        Ok(Answer::Unsigned(number)) => format!("unsigned\t{number}"),
+        Ok(Answer::Signed(number)) => format!("signed\t{number}"),
+        Ok(Answer::Text(text)) => format!("text\t{}", escape(text)),
+        Ok(Answer::Unsolved) => "unsolved".to_string(),
+        Err(Failure::Timeout) => "timeout".to_string(),
+        Err(Failure::Skipped) => "skipped".to_string(),
+        Err(Failure::Panicked { message, location }) => {
+            format!("panicked\t{}\t{}", escape(message), escape(location.as_deref().unwrap_or_default()))
+        }
+        Err(Failure::Parse(err)) => format!(
+            "parse\t{}\t{}\t{}\t{}\t{}\t{}",
+            err.line,
+            err.column,
+            err.width,
+            escape(&err.snippet),
+            escape(&err.expected),
+            escape(&err.found)
+        ),
+    };
+    format!("phase\t{}\t{cycles}\t{cpu}\t{allocations}\t{outcome}", result.duration.as_nanos())
+}
+
+/// Reverses [`encode_phase`], given the fields after `phase`
+fn decode_phase<'a>(mut fields: impl Iterator<Item = &'a str>) -> Option<PhaseResult> {
+    let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+    let duration = nanos(fields.next()?)?;
+    let cycles = match fields.next()? {
+        "-" => None,
+        cycles => Some(cycles.parse().ok()?),
+    };
+    let cpu = match fields.next()? {
+        "-" => None,
+        cpu => Some(nanos(cpu)?),
+    };
+    let allocations = match fields.next()? {
+        "-" => None,
+        stats => {
+            let mut numbers = stats.split(',').map(str::parse);
+            Some(AllocStats {
+                allocations: numbers.next()?.ok()?,
+                bytes: numbers.next()?.ok()?,
+                peak_bytes: numbers.next()?.ok()?,
+            })
+        }
+    };
+    let outcome = match fields.next()? {
+        "unsigned" => Ok(Answer::Unsigned(fields.next()?.parse().ok()?)),
+        "signed" => Ok(Answer::Signed(fields.next()?.parse().ok()?)),
+        "text" => Ok(Answer::Text(unescape(fields.next()?))),
+        "unsolved" => Ok(Answer::Unsolved),
+        "timeout" => Err(Failure::Timeout),
+        "skipped" => Err(Failure::Skipped),
+        "panicked" => Err(Failure::Panicked {
+            message: unescape(fields.next()?),
+            location: Some(unescape(fields.next()?)).filter(|location| !location.is_empty()),
+        }),
+        "parse" => Err(Failure::Parse(ParseError {
+            line: fields.next()?.parse().ok()?,
+            column: fields.next()?.parse().ok()?,
+            width: fields.next()?.parse().ok()?,
+            snippet: unescape(fields.next()?),
+            expected: unescape(fields.next()?),
+            found: unescape(fields.next()?),
+        })),
+        _ => return None,
+    };
+    Some(PhaseResult {
+        outcome,
+        duration,
+        cycles,
+        cpu,
+        allocations,
+    })
+}
+
+/// One `span` line: id, parent, start relative to `origin`, duration and name
+fn encode_span(record: &SpanRecord, origin: Instant) -> String {
+    format!(
+        "span\t{}\t{}\t{}\t{}\t{}",
+        record.id,
+        record.parent.map_or("-".to_string(), |parent| parent.to_string()),
+        record.start.saturating_duration_since(origin).as_nanos(),
+        record.duration.as_nanos(),
+        escape(&record.name)
+    )
+}
+
+/// Reverses [`encode_span`], given the fields after `span`, as a span of the calling thread
+fn decode_span<'a>(mut fields: impl Iterator<Item = &'a str>, origin: Instant) -> Option<SpanRecord> {
+    let id = fields.next()?.parse().ok()?;
+    let parent = match fields.next()? {
+        "-" => None,
+        parent => Some(parent.parse().ok()?),
+    };
+    let start = origin + Duration::from_nanos(fields.next()?.parse().ok()?);
+    let duration = Duration::from_nanos(fields.next()?.parse().ok()?);
+    let thread = thread::current();
+    Some(SpanRecord {
+        id,
+        parent,
+        name: Cow::Owned(unescape(fields.next()?)),
+        thread: thread.id(),
+        thread_name: thread.name().map(str::to_string),
+        start,
+        duration,
+    })
+}
+
+#[test]
+fn test_encoding() {
+    let results = [
+        PhaseResult {
+            outcome: Ok(Answer::Text("a\tb\\n\nc".to_string())),
+            duration: Duration::from_nanos(1_234),
+            cycles: Some(4_321),
+            cpu: Some(Duration::from_nanos(1_000)),
+            allocations: Some(AllocStats {
+                allocations: 3,
+                bytes: 96,
+                peak_bytes: 64,
+            }),
+        },
+        PhaseResult {
+            outcome: Ok(Answer::Signed(-7)),
+            duration: Duration::from_millis(2),
+            cycles: None,
+            cpu: None,
+            allocations: None,
+        },
+        PhaseResult::failed(
+            Failure::Panicked {
+                message: "Unknown operator\n".to_string(),
+                location: None,
+            },
+            Duration::ZERO,
+        ),
+        PhaseResult::failed(
+            Failure::Parse(ParseError {
+                line: 2,
+                column: 3,
+                snippet: "1,\tx".to_string(),
+                width: 1,
+                expected: "a number".to_string(),
+                found: "`x`".to_string(),
+            }),
+            Duration::from_micros(5),
+        ),
+    ];
+    for result in results {
+        let line = encode_phase(&result);
+        assert!(!line.contains('\n'), "{line}");
+        let decoded = decode_phase(line.split('\t').skip(1)).unwrap();
+        assert_eq!(decoded.outcome, result.outcome);
+        assert_eq!(decoded.times(), result.times());
+        assert_eq!(decoded.allocations.map(|stats| stats.bytes), result.allocations.map(|stats| stats.bytes));
+    }
+    assert!(decode_phase("12\t-\t-\t-\tmystery".split('\t')).is_none());
+}
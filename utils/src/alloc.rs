@@ -0,0 +1,211 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Most [`AllocMeter`]s that can run at once, one per bit of [`METERS`]
+const METER_SLOTS: usize = 64;
+/// Bit `i` is set while slot `i` of [`PEAKS`] belongs to a running meter
+static METERS: AtomicU64 = AtomicU64::new(0);
+/// Highest number of live bytes since each running meter's last lap
+static PEAKS: [AtomicUsize; METER_SLOTS] = [const { AtomicUsize::new(0) }; METER_SLOTS];
+
+/// Global allocator that counts allocations on top of the system allocator
+///
+/// Opt in from a binary or library with
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: utils::alloc::CountingAllocator = utils::alloc::CountingAllocator;
+/// ```
+/// and measure with [`AllocMeter`]. Counters are process-wide, so
+/// allocations made by other threads during a measurement are included.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // A reallocation counts as a fresh allocation of the new size
+            LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    let mut meters = METERS.load(Ordering::Relaxed);
+    while meters != 0 {
+        PEAKS[meters.trailing_zeros() as usize].fetch_max(live, Ordering::Relaxed);
+        meters &= meters - 1;
+    }
+}
+
+/// Returns whether [`CountingAllocator`] is the global allocator of this process
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Allocation activity between two points in time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting each reallocation as one
+    pub allocations: usize,
+    /// Total bytes requested by those allocations
+    pub bytes: usize,
+    /// Highest number of live bytes reached, above what was live at the start
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            report_bytes(self.bytes),
+            report_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Measures allocation activity the way [`Stopwatch::lap`](crate::structs::stopwatch::typed::Stopwatch::lap) measures time
+///
+/// Each running meter tracks its own peak, so meters can overlap, e.g. on
+/// different threads, without resetting each other's.
+pub struct AllocMeter {
+    /// Index into [`PEAKS`], given back when the meter is dropped
+    slot: usize,
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+impl AllocMeter {
+    /// Starts measuring, or returns `None` if [`CountingAllocator`] isn't installed
+    /// or [`METER_SLOTS`] meters are already running
+    pub fn start() -> Option<Self> {
+        if !is_installed() {
+            return None;
+        }
+        let mut meters = METERS.load(Ordering::Relaxed);
+        let slot = loop {
+            let slot = meters.trailing_ones() as usize;
+            if slot == METER_SLOTS {
+                return None;
+            }
+            // The peak starts from what's live now, before allocations start raising it
+            PEAKS[slot].store(LIVE_BYTES.load(Ordering::Relaxed), Ordering::Relaxed);
+            match METERS.compare_exchange_weak(meters, meters | 1 << slot, Ordering::AcqRel, Ordering::Relaxed) {
+                Ok(_) => break slot,
+                Err(current) => meters = current,
+            }
+        };
+        let (allocations, bytes, live) = Self::counters();
+        Some(Self {
+            slot,
+            allocations,
+            bytes,
+            live,
+        })
+    }
+
+    fn counters() -> (usize, usize, usize) {
+        (
+            ALLOCATIONS.load(Ordering::Relaxed),
+            ALLOCATED_BYTES.load(Ordering::Relaxed),
+            LIVE_BYTES.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Returns the activity since the meter was started or last lapped, and starts a new lap
+    pub fn lap(&mut self) -> AllocStats {
+        let (allocations, bytes, live) = Self::counters();
+        let peak = PEAKS[self.slot].swap(live, Ordering::Relaxed);
+        let stats = AllocStats {
+            allocations: allocations - self.allocations,
+            bytes: bytes - self.bytes,
+            peak_bytes: peak.max(live).saturating_sub(self.live),
+        };
+        (self.allocations, self.bytes, self.live) = (allocations, bytes, live);
+        stats
+    }
+}
+
+impl Drop for AllocMeter {
+    fn drop(&mut self) {
+        METERS.fetch_and(!(1 << self.slot), Ordering::AcqRel);
+    }
+}
+
+/// Formats a byte count using binary units
+pub fn report_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+#[cfg(test)]
+#[global_allocator]
+static TEST_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_alloc_meter() {
+    let mut meter = AllocMeter::start().expect("counting allocator is installed for tests");
+    let buffer: Vec<u8> = Vec::with_capacity(64 * 1024);
+    drop(std::hint::black_box(buffer));
+    let stats = meter.lap();
+    assert!(stats.allocations >= 1);
+    assert!(stats.bytes >= 64 * 1024);
+    assert!(stats.peak_bytes >= 64 * 1024);
+
+    // A meter started later doesn't reset the peak of one that's running
+    let mut outer = AllocMeter::start().unwrap();
+    let buffer: Vec<u8> = Vec::with_capacity(256 * 1024);
+    drop(std::hint::black_box(buffer));
+    let mut inner = AllocMeter::start().unwrap();
+    let small: Vec<u8> = Vec::with_capacity(1024);
+    drop(std::hint::black_box(small));
+    assert!(inner.lap().peak_bytes < 256 * 1024);
+    assert!(outer.lap().peak_bytes >= 256 * 1024);
+
+    assert_eq!(report_bytes(512), "512B");
+    assert_eq!(report_bytes(64 * 1024), "64.0KiB");
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle
+///
+/// Integers of every width convert into it with `From`, so a part can keep
+/// returning `u64`, `i64` or `u128`, and letter-grid puzzles can answer
+/// with text. Parts that aren't solved yet return [`Answer::Unsolved`]
+/// rather than a placeholder that looks like a real answer.
+#[derive(Clone, Debug, Default, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    /// Returns whether this answer agrees with an answer recorded as text, e.g. in `answers.txt`
+    ///
+    /// An unsolved part never matches.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Answer::Unsigned(number) => expected.parse() == Ok(*number),
+            Answer::Signed(number) => expected.parse() == Ok(*number),
+            Answer::Text(text) => text == expected,
+            Answer::Unsolved => false,
+        }
+    }
+
+    /// Returns whether the part has been solved
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+}
+
+impl PartialEq for Answer {
+    /// Integers compare by value, whichever variant holds them
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Unsigned(a)) => {
+                u128::try_from(*b).is_ok_and(|b| *a == b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(number) => write!(f, "{number}"),
+            Answer::Signed(number) => write!(f, "{number}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+macro_rules! from_integers {
+    ($variant:ident as $wide:ty: $($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(number: $int) -> Self {
+                    Answer::$variant(number as $wide)
+                }
+            }
+        )*
+    };
+}
+
+from_integers!(Unsigned as u128: u8, u16, u32, u64, u128, usize);
+from_integers!(Signed as i128: i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[test]
+fn test_answer() {
+    assert_eq!(Answer::from(9194i64), Answer::from(9194u64));
+    assert_ne!(Answer::from(-1i64), Answer::from(u128::MAX));
+    assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+    assert_eq!(Answer::from(170449335646486u64), "170449335646486");
+    assert_eq!(Answer::from("EFGH"), "EFGH");
+    assert!(!Answer::Unsolved.matches("unsolved"));
+    assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+}
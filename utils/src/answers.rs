@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Expected puzzle answers keyed by year, day and part
+///
+/// Stored as plain text with one answer per line, `<year> <day> <part> <answer>`.
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u16, u8, u8), String>);
+
+/// Error returned when a line of an answers file can't be parsed
+#[derive(Debug)]
+pub struct AnswersError {
+    /// 1-based line number of the offending line
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    /// Reads and parses an answers file
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        Ok(text.parse()?)
+    }
+
+    /// Returns the expected answer for a part, if one is recorded
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Records the expected answer for a part, replacing any previous one
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, answer: impl Into<String>) {
+        self.0.insert((year, day, part), answer.into());
+    }
+
+    /// Iterates over `((year, day, part), answer)` in chronological order
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8, u8), &str)> {
+        self.0.iter().map(|(&key, answer)| (key, answer.as_str()))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| AnswersError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let mut number = |name: &str| {
+                fields
+                    .next()
+                    .and_then(|field| field.parse::<u16>().ok())
+                    .ok_or_else(|| error(&format!("expected {name}")))
+            };
+            let year = number("year")?;
+            let day = number("day")?;
+            let part = number("part")?;
+            let answer = fields
+                .next()
+                .map(str::trim)
+                .filter(|answer| !answer.is_empty())
+                .ok_or_else(|| error("expected answer"))?;
+            if !(1..=25).contains(&day) || !(1..=2).contains(&part) {
+                return Err(error("day must be 1-25 and part 1-2"));
+            }
+            answers.insert(year, day as u8, part as u8, answer);
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year day part answer")?;
+        for ((year, day, part), answer) in self.iter() {
+            writeln!(f, "{year} {day} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let answers: Answers = "# comment\n2025 1 1 1036\n\n2025 1 2 6228\n".parse().unwrap();
+    assert_eq!(answers.get(2025, 1, 1), Some("1036"));
+    assert_eq!(answers.get(2025, 1, 2), Some("6228"));
+    assert_eq!(answers.get(2025, 2, 1), None);
+    assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+}
+
+#[test]
+fn test_bad_line() {
+    let err = "2025 1 1 1036\n2025 x 1 5\n".parse::<Answers>().unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.to_string(), "line 2: expected day");
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::bench::Stats;
+
+/// Significance level below which a change in timings is taken to be real
+pub const ALPHA: f64 = 0.05;
+
+/// Saved benchmark results keyed by year and day
+///
+/// Stored as plain text, one section per day:
+/// ```text
+/// [2025 3]
+/// revision 3c12715
+/// machine x86_64-linux, 8 threads, AMD Ryzen 7 5800X
+/// # parse: min 40μs  median 41μs  mean 41μs ± 1μs  p95 43μs
+/// parse 40123 41012 ...
+/// ```
+/// where each phase line lists its samples in nanoseconds. Blank lines
+/// and lines starting with `#` are ignored, the summaries in comments are
+/// only there for people reading the file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Baselines(BTreeMap<(u16, u8), Baseline>);
+
+/// Benchmark results of one day
+#[derive(Clone, Debug, PartialEq)]
+pub struct Baseline {
+    /// Git revision the benchmark was run at
+    pub revision: String,
+    /// Description of the machine the benchmark was run on, see [`machine_fingerprint`]
+    pub machine: String,
+    /// Timings of each phase, such as `parse` or `part1`, in the order they were run
+    pub phases: Vec<(String, Stats)>,
+}
+
+impl Baseline {
+    /// Returns the timings of `phase`, if it was benchmarked
+    pub fn phase(&self, phase: &str) -> Option<&Stats> {
+        self.phases
+            .iter()
+            .find(|(name, _)| name == phase)
+            .map(|(_, stats)| stats)
+    }
+}
+
+/// Error returned when a line of a baselines file can't be parsed
+#[derive(Debug)]
+pub struct BaselineError {
+    /// 1-based line number of the offending line
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baselines {
+    /// Reads and parses a baselines file
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        text.parse()
+            .map_err(|err| format!("{}: {err}", path.display()).into())
+    }
+
+    /// Returns the baseline of a day, if one is recorded
+    pub fn get(&self, year: u16, day: u8) -> Option<&Baseline> {
+        self.0.get(&(year, day))
+    }
+
+    /// Records the baseline of a day, replacing any previous one
+    pub fn insert(&mut self, year: u16, day: u8, baseline: Baseline) {
+        self.0.insert((year, day), baseline);
+    }
+}
+
+impl FromStr for Baselines {
+    type Err = BaselineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baselines = Baselines::default();
+        let mut current: Option<((u16, u8), Baseline)> = None;
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| BaselineError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            if let Some(header) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                let key = header
+                    .split_once(' ')
+                    .and_then(|(year, day)| Some((year.parse().ok()?, day.trim().parse().ok()?)))
+                    .ok_or_else(|| error("expected [<year> <day>]"))?;
+                if let Some((key, baseline)) = current.take() {
+                    baselines.0.insert(key, baseline);
+                }
+                let baseline = Baseline {
+                    revision: String::new(),
+                    machine: String::new(),
+                    phases: Vec::new(),
+                };
+                current = Some((key, baseline));
+                continue;
+            }
+            let Some((_, baseline)) = current.as_mut() else {
+                return Err(error("expected [<year> <day>] before any results"));
+            };
+            let (name, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match name {
+                "revision" => baseline.revision = rest.trim().to_string(),
+                "machine" => baseline.machine = rest.trim().to_string(),
+                _ => {
+                    let samples = rest
+                        .split_whitespace()
+                        .map(|sample| sample.parse().map(Duration::from_nanos))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| error(&format!("{name} samples must be nanoseconds")))?;
+                    if samples.is_empty() {
+                        return Err(error(&format!("{name} has no samples")));
+                    }
+                    baseline
+                        .phases
+                        .push((name.to_string(), Stats::from_samples(samples)));
+                }
+            }
+        }
+        if let Some((key, baseline)) = current {
+            baselines.0.insert(key, baseline);
+        }
+        Ok(baselines)
+    }
+}
+
+impl fmt::Display for Baselines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# aoc bench baselines, samples in nanoseconds")?;
+        for ((year, day), baseline) in &self.0 {
+            writeln!(f, "\n[{year} {day}]")?;
+            writeln!(f, "revision {}", baseline.revision)?;
+            writeln!(f, "machine {}", baseline.machine)?;
+            for (name, stats) in &baseline.phases {
+                writeln!(f, "# {name}: {stats}")?;
+                let samples: Vec<String> = stats
+                    .samples
+                    .iter()
+                    .map(|sample| sample.as_nanos().to_string())
+                    .collect();
+                writeln!(f, "{name} {}", samples.join(" "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Describes the current machine, so baselines from different machines aren't compared blindly
+///
+/// # Returns
+/// The architecture, operating system, number of hardware threads and,
+/// where `/proc/cpuinfo` exists, the CPU model
+pub fn machine_fingerprint() -> String {
+    let threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let mut fingerprint = format!(
+        "{}-{}, {threads} threads",
+        std::env::consts::ARCH,
+        std::env::consts::OS
+    );
+    let model = std::fs::read_to_string("/proc/cpuinfo").ok().and_then(|cpuinfo| {
+        cpuinfo.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "model name").then(|| value.trim().to_string())
+        })
+    });
+    if let Some(model) = model {
+        fingerprint.push_str(", ");
+        fingerprint.push_str(&model);
+    }
+    fingerprint
+}
+
+/// Whether a phase got slower or faster than its baseline
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+/// Outcome of comparing new timings of a phase against its baseline
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    /// Relative change of the median, `0.1` meaning 10% slower
+    pub change: f64,
+    /// Two-sided p-value of the Mann-Whitney U test between the two sets of samples
+    pub p_value: f64,
+    pub verdict: Verdict,
+}
+
+impl Comparison {
+    /// Compares `new` timings against `old` ones
+    ///
+    /// A phase only counts as slower or faster when the samples differ
+    /// significantly (`p < ALPHA`) *and* the median moved by more than
+    /// `threshold`, given as a fraction. The Mann-Whitney U test is used
+    /// rather than a t-test because timings are skewed by outliers.
+    pub fn new(old: &Stats, new: &Stats, threshold: f64) -> Self {
+        let old_median = old.median.as_nanos() as f64;
+        let change = if old_median > 0.0 {
+            new.median.as_nanos() as f64 / old_median - 1.0
+        } else {
+            0.0
+        };
+        let p_value = mann_whitney_p(&old.samples, &new.samples);
+        let verdict = if p_value >= ALPHA || change.abs() <= threshold {
+            Verdict::Unchanged
+        } else if change > 0.0 {
+            Verdict::Slower
+        } else {
+            Verdict::Faster
+        };
+        Self {
+            change,
+            p_value,
+            verdict,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self.verdict {
+            Verdict::Slower => "SLOWER",
+            Verdict::Faster => "faster",
+            Verdict::Unchanged => "no change",
+        };
+        write!(
+            f,
+            "{:+.1}% (p = {:.3}) {verdict}",
+            self.change * 100.0,
+            self.p_value
+        )
+    }
+}
+
+/// Two-sided p-value of the Mann-Whitney U test, using the normal approximation
+fn mann_whitney_p(a: &[Duration], b: &[Duration]) -> f64 {
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    let mut combined: Vec<(Duration, bool)> = a
+        .iter()
+        .map(|&sample| (sample, true))
+        .chain(b.iter().map(|&sample| (sample, false)))
+        .collect();
+    combined.sort_unstable_by_key(|&(sample, _)| sample);
+
+    // Tied samples share the average of the ranks they span
+    let mut rank_sum = 0.0;
+    let mut tie_correction = 0.0;
+    let mut start = 0;
+    while start < combined.len() {
+        let end = start
+            + combined[start..]
+                .iter()
+                .take_while(|(sample, _)| *sample == combined[start].0)
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        rank_sum += rank * combined[start..end].iter().filter(|(_, first)| *first).count() as f64;
+        let ties = (end - start) as f64;
+        tie_correction += ties.powi(3) - ties;
+        start = end;
+    }
+
+    let u = rank_sum - n1 * (n1 + 1.0) / 2.0;
+    let n = n1 + n2;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_correction / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        return 1.0;
+    }
+    let z = (u - n1 * n2 / 2.0).abs() / variance.sqrt();
+    (2.0 * (1.0 - normal_cdf(z))).clamp(0.0, 1.0)
+}
+
+/// Standard normal CDF, via the Abramowitz and Stegun approximation of `erf`
+fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t
+        * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
+
+#[test]
+fn test_round_trip() {
+    let stats = |micros: u64| Stats::from_samples((0..5).map(|i| Duration::from_micros(micros + i)).collect());
+    let mut baselines = Baselines::default();
+    baselines.insert(
+        2025,
+        3,
+        Baseline {
+            revision: "3c12715-dirty".to_string(),
+            machine: "x86_64-linux, 8 threads, Some CPU".to_string(),
+            phases: vec![("parse".to_string(), stats(40)), ("part1".to_string(), stats(500))],
+        },
+    );
+    let text = baselines.to_string();
+    assert!(text.contains("\n[2025 3]\nrevision 3c12715-dirty\n"));
+    assert_eq!(text.parse::<Baselines>().unwrap(), baselines);
+    assert_eq!(
+        "parse 1 2\n".parse::<Baselines>().unwrap_err().to_string(),
+        "line 1: expected [<year> <day>] before any results"
+    );
+}
+
+#[test]
+fn test_comparison() {
+    let samples = |micros: u64| {
+        Stats::from_samples((0..25).map(|i| Duration::from_micros(micros + i % 5)).collect())
+    };
+    let slower = Comparison::new(&samples(100), &samples(150), 0.05);
+    assert_eq!(slower.verdict, Verdict::Slower);
+    assert!(slower.p_value < 0.001);
+    assert_eq!(Comparison::new(&samples(150), &samples(100), 0.05).verdict, Verdict::Faster);
+    assert_eq!(Comparison::new(&samples(100), &samples(101), 0.05).verdict, Verdict::Unchanged);
+    assert_eq!(Comparison::new(&samples(100), &samples(100), 0.0).verdict, Verdict::Unchanged);
+}
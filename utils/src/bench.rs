@@ -0,0 +1,152 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::Duration;
+
+use crate::structs::stopwatch::ReportDuration;
+use crate::structs::stopwatch::typed::Stopwatch;
+
+/// Repeatedly times a closure to get statistics rather than a single noisy sample
+///
+/// The closure is run `warmup` times without being measured, then
+/// `iterations` times with each run timed as one [`Stopwatch`] lap.
+#[derive(Clone, Copy, Debug)]
+pub struct Bench {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 25,
+        }
+    }
+}
+
+impl Bench {
+    /// Runs `f` through the warm-up and timed iterations and summarises the timings
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+        let mut samples = Vec::with_capacity(self.iterations);
+        let mut watch = Stopwatch::started();
+        for _ in 0..self.iterations {
+            black_box(f());
+            samples.push(watch.lap());
+        }
+        Stats::from_samples(samples)
+    }
+}
+
+/// Summary statistics of a set of timing samples
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// The samples, sorted from fastest to slowest
+    pub samples: Vec<Duration>,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    /// Samples below `Q1 - 1.5 * IQR`
+    pub low_outliers: usize,
+    /// Samples above `Q3 + 1.5 * IQR`
+    pub high_outliers: usize,
+}
+
+impl Stats {
+    /// Computes the statistics of `samples`
+    ///
+    /// # Panics
+    /// If `samples` is empty
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+        samples.sort_unstable();
+        let n = samples.len();
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&samples, 25.0).as_nanos() as f64;
+        let q3 = percentile(&samples, 75.0).as_nanos() as f64;
+        let iqr = q3 - q1;
+        let low_outliers = nanos.iter().filter(|&&x| x < q1 - 1.5 * iqr).count();
+        let high_outliers = nanos.iter().filter(|&&x| x > q3 + 1.5 * iqr).count();
+
+        Self {
+            min: samples[0],
+            median: percentile(&samples, 50.0),
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            p95: percentile(&samples, 95.0),
+            max: samples[n - 1],
+            low_outliers,
+            high_outliers,
+            samples,
+        }
+    }
+
+    /// Total number of samples flagged as outliers
+    pub fn outliers(&self) -> usize {
+        self.low_outliers + self.high_outliers
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {}  median {}  mean {} ± {}  p95 {}",
+            self.min.report(),
+            self.median.report(),
+            self.mean.report(),
+            self.stddev.report(),
+            self.p95.report()
+        )?;
+        if self.outliers() > 0 {
+            write!(
+                f,
+                "  ({} outliers: {} low, {} high)",
+                self.outliers(),
+                self.low_outliers,
+                self.high_outliers
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Nearest-rank percentile of already sorted samples
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[test]
+fn test_stats() {
+    let samples = (1..=20).map(Duration::from_micros).collect();
+    let stats = Stats::from_samples(samples);
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(10));
+    assert_eq!(stats.mean, Duration::from_nanos(10_500));
+    assert_eq!(stats.p95, Duration::from_micros(19));
+    assert_eq!(stats.max, Duration::from_micros(20));
+    assert_eq!(stats.stddev, Duration::from_nanos(5_916));
+    assert_eq!(stats.outliers(), 0);
+}
+
+#[test]
+fn test_outliers() {
+    let mut samples: Vec<Duration> = vec![Duration::from_micros(10); 10];
+    samples.push(Duration::from_millis(5));
+    let stats = Stats::from_samples(samples);
+    assert_eq!((stats.low_outliers, stats.high_outliers), (0, 1));
+    assert!(stats.to_string().ends_with("(1 outliers: 0 low, 1 high)"));
+}
@@ -0,0 +1,89 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+thread_local! {
+    /// Token of the part running on this thread, if any
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Lets a runner ask a solution running on another thread to give up
+///
+/// Cancellation is cooperative: the runner [installs](CancellationToken::install)
+/// the token on the thread running a part, and long-running solutions poll
+/// it with [`is_cancelled`] or bail out with [`checkpoint`]. Threads spawned
+/// by a solution don't inherit the token.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token that hasn't been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks whoever is polling this token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether [`CancellationToken::cancel`] has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Makes this the token polled by [`is_cancelled`] and [`checkpoint`] on the current thread
+    ///
+    /// # Returns
+    /// A guard that restores the previously installed token when dropped
+    pub fn install(&self) -> InstalledToken {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        InstalledToken { previous }
+    }
+}
+
+/// Keeps a [`CancellationToken`] installed on the current thread until dropped
+#[must_use = "the token is uninstalled as soon as the guard is dropped"]
+pub struct InstalledToken {
+    previous: Option<CancellationToken>,
+}
+
+impl Drop for InstalledToken {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Panic payload [`checkpoint`] unwinds with, so runners can tell a cancelled part from a crashed one
+#[derive(Debug)]
+pub struct Cancelled;
+
+/// Returns whether the token installed on the current thread has been cancelled
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(CancellationToken::is_cancelled))
+}
+
+/// Unwinds with a [`Cancelled`] payload if the current part has been cancelled
+///
+/// Meant for the outer loops of slow solutions. Unwinding doesn't run the
+/// panic hook, so nothing is printed, and the runner catches it.
+pub fn checkpoint() {
+    if is_cancelled() {
+        std::panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[test]
+fn test_checkpoint() {
+    let token = CancellationToken::new();
+    checkpoint();
+    {
+        let _installed = token.install();
+        checkpoint();
+        token.cancel();
+        assert!(is_cancelled());
+        let unwound = std::panic::catch_unwind(checkpoint).unwrap_err();
+        assert!(unwound.is::<Cancelled>());
+    }
+    assert!(!is_cancelled());
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory laid out as `<year>/day<day>/input`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Explicit input path that reads standard input instead of a file
+pub const STDIN: &str = "-";
+
+/// Finds a day's puzzle input independently of the working directory
+///
+/// Candidates are tried in order:
+/// 1. An explicit path, e.g. from an `--input` flag. When given, it is the only candidate,
+///    and [`STDIN`] reads standard input.
+/// 2. `$AOC_INPUT_DIR/<year>/day<day>/input`
+/// 3. `<manifest dir>/<year>/day<day>/input`
+///
+/// With [`InputLocator::with_example`], the last two look for the numbered
+/// example `example<n>` next to `input` instead.
+#[derive(Clone, Debug)]
+pub struct InputLocator {
+    manifest_dir: PathBuf,
+    explicit: Option<PathBuf>,
+    example: Option<u8>,
+}
+
+/// Error returned when none of the candidate paths could be read
+#[derive(Debug)]
+pub struct InputError {
+    pub year: u16,
+    pub day: u8,
+    /// Every path that was tried, with the reason it was rejected
+    pub tried: Vec<(PathBuf, std::io::Error)>,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no input found for {} day {}, tried:", self.year, self.day)?;
+        for (path, err) in &self.tried {
+            write!(f, "\n  {} ({})", path.display(), err)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl InputLocator {
+    /// Creates a locator that falls back to inputs stored under `manifest_dir`
+    ///
+    /// Callers normally pass `env!("CARGO_MANIFEST_DIR")`.
+    pub fn new(manifest_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            manifest_dir: manifest_dir.into(),
+            explicit: None,
+            example: None,
+        }
+    }
+
+    /// Uses `path` instead of searching, if one is given
+    pub fn with_input(mut self, path: Option<impl Into<PathBuf>>) -> Self {
+        self.explicit = path.map(Into::into);
+        self
+    }
+
+    /// Looks for the numbered example fixture instead of the real input, if one is given
+    pub fn with_example(mut self, example: Option<u8>) -> Self {
+        self.example = example;
+        self
+    }
+
+    /// Returns the paths that will be tried for `year`/`day`, in order
+    pub fn candidates(&self, year: u16, day: u8) -> Vec<PathBuf> {
+        self.candidates_with(year, day, std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+    }
+
+    fn candidates_with(&self, year: u16, day: u8, input_dir: Option<PathBuf>) -> Vec<PathBuf> {
+        if let Some(path) = &self.explicit {
+            return vec![path.clone()];
+        }
+        input_dir
+            .iter()
+            .chain(std::iter::once(&self.manifest_dir))
+            .map(|dir| match self.example {
+                Some(example) => day_example(dir, year, day, example),
+                None => day_input(dir, year, day),
+            })
+            .collect()
+    }
+
+    /// Returns the first candidate path that exists as a file
+    pub fn locate(&self, year: u16, day: u8) -> Result<PathBuf, InputError> {
+        self.read_with(year, day, |path| {
+            if path.is_file() {
+                Ok(())
+            } else {
+                Err(std::io::Error::from(std::io::ErrorKind::NotFound))
+            }
+        })
+        .map(|(path, ())| path)
+    }
+
+    /// Reads the first candidate that can be read
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        self.load(year, day).map(|(_, input)| input)
+    }
+
+    /// Reads the first candidate that can be read, along with its path
+    ///
+    /// Standard input is reported with the path `<stdin>`.
+    pub fn load(&self, year: u16, day: u8) -> Result<(PathBuf, String), InputError> {
+        if self.explicit.as_deref() == Some(Path::new(STDIN)) {
+            let mut input = String::new();
+            return match std::io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok((PathBuf::from("<stdin>"), input)),
+                Err(err) => Err(InputError {
+                    year,
+                    day,
+                    tried: vec![(PathBuf::from(STDIN), err)],
+                }),
+            };
+        }
+        self.read_with(year, day, |path| std::fs::read_to_string(path))
+    }
+
+    fn read_with<T>(
+        &self,
+        year: u16,
+        day: u8,
+        mut read: impl FnMut(&Path) -> std::io::Result<T>,
+    ) -> Result<(PathBuf, T), InputError> {
+        let mut tried = Vec::new();
+        for path in self.candidates(year, day) {
+            match read(&path) {
+                Ok(value) => return Ok((path, value)),
+                Err(err) => tried.push((path, err)),
+            }
+        }
+        Err(InputError { year, day, tried })
+    }
+}
+
+/// Returns whether `path` holds an input, that is a file with something in it
+///
+/// Empty files are placeholders left by scaffolding, not inputs.
+pub fn is_saved(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0)
+}
+
+/// Path of a day's input within a directory laid out as `<year>/day<day>/input`
+pub fn day_input(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{day}")).join("input")
+}
+
+/// Path of a day's numbered example within a directory laid out as `<year>/day<day>/example<n>`
+pub fn day_example(dir: &Path, year: u16, day: u8, example: u8) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day{day}"))
+        .join(format!("example{example}"))
+}
+
+#[test]
+fn test_candidates() {
+    let locator = InputLocator::new("/repo");
+    assert_eq!(
+        locator.candidates_with(2025, 3, Some(PathBuf::from("/inputs"))),
+        vec![
+            PathBuf::from("/inputs/2025/day3/input"),
+            PathBuf::from("/repo/2025/day3/input"),
+        ]
+    );
+    let locator = locator.with_input(Some("/tmp/custom"));
+    assert_eq!(
+        locator.candidates_with(2025, 3, Some(PathBuf::from("/inputs"))),
+        vec![PathBuf::from("/tmp/custom")]
+    );
+    let locator = InputLocator::new("/repo").with_example(Some(2));
+    assert_eq!(
+        locator.candidates_with(2025, 3, None),
+        vec![PathBuf::from("/repo/2025/day3/example2")]
+    );
+}
+
+#[test]
+fn test_error_names_every_path() {
+    let locator = InputLocator::new("/nonexistent/repo");
+    let err = locator.read(2025, 3).unwrap_err();
+    assert!(err.to_string().contains("/nonexistent/repo/2025/day3/input"));
+    assert_eq!(err.tried.len(), locator.candidates(2025, 3).len());
+}
@@ -1,2 +1,11 @@
 #![allow(dead_code)]
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod cancel;
+pub mod input;
+pub mod parse;
+pub mod solution;
 pub mod structs;
\ No newline at end of file
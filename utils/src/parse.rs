@@ -0,0 +1,172 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a puzzle input couldn't be parsed, and where
+///
+/// Built with the helpers on [`Source`], which work out the line and
+/// column from where the offending slice sits in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The whole offending line
+    pub snippet: String,
+    /// Number of characters to underline, at least one
+    pub width: usize,
+    /// What the parser was looking for, e.g. "a number"
+    pub expected: String,
+    /// What it found instead, e.g. "`x1`" or "end of line"
+    pub found: String,
+}
+
+impl ParseError {
+    /// Renders the error the way rustc does, with a caret under the bad spot
+    ///
+    /// # Examples
+    /// ```
+    /// use utils::parse::Source;
+    ///
+    /// let source = Source::new("1,2\n3,x\n");
+    /// let err = source.parse::<u64>(&source.input()[6..7], "a number").unwrap_err();
+    /// assert_eq!(
+    ///     err.render("input"),
+    ///     "error: expected a number, found `x`\n --> input:2:3\n  |\n2 | 3,x\n  |   ^\n"
+    /// );
+    /// ```
+    pub fn render(&self, source_name: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "error: expected {}, found {}\n{gutter}--> {source_name}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}\n",
+            self.expected,
+            self.found,
+            self.line,
+            self.column,
+            self.line,
+            self.snippet,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A puzzle input being parsed, for building [`ParseError`]s that point into it
+///
+/// The helpers take slices of the input, as produced by `lines`, `split`,
+/// `trim` and friends, and locate them by their address. A slice that
+/// doesn't come from the input is reported at the start of it.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// The whole input
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// An error pointing at `at`, which was found where `expected` should have been
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let found = if at.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("`{at}`")
+        };
+        self.error_at(self.offset(at), at.chars().count(), expected.into(), found)
+    }
+
+    /// An error pointing just past the end of `at`, where `expected` is missing
+    pub fn missing_after(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let end = self.offset(at) + at.len();
+        let found = match self.input[end..].chars().next() {
+            None | Some('\n' | '\r') => "end of line".to_string(),
+            Some(c) => format!("`{c}`"),
+        };
+        self.error_at(end, 1, expected.into(), found)
+    }
+
+    /// Parses `field` with [`FromStr`], failing with an error that points at it
+    pub fn parse<T: FromStr>(&self, field: &str, expected: &str) -> Result<T, ParseError> {
+        field.parse().map_err(|_| self.error(field, expected))
+    }
+
+    /// Takes the next item of `fields`, failing with an error just after `previous` if there isn't one
+    ///
+    /// `previous` is typically the line being split, or the last field taken from it.
+    pub fn next<'b>(
+        &self,
+        fields: &mut impl Iterator<Item = &'b str>,
+        previous: &str,
+        expected: &str,
+    ) -> Result<&'b str, ParseError> {
+        fields
+            .next()
+            .ok_or_else(|| self.missing_after(previous, expected))
+    }
+
+    /// Byte offset of `at` in the input, or 0 if it isn't part of it
+    fn offset(&self, at: &str) -> usize {
+        let start = self.input.as_ptr() as usize;
+        let at_start = at.as_ptr() as usize;
+        if at_start >= start && at_start + at.len() <= start + self.input.len() {
+            at_start - start
+        } else {
+            0
+        }
+    }
+
+    fn error_at(&self, offset: usize, width: usize, expected: String, found: String) -> ParseError {
+        let line_start = self.input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = self.input[offset..]
+            .find('\n')
+            .map_or(self.input.len(), |newline| offset + newline);
+        let snippet = self.input[line_start..line_end].trim_end_matches('\r');
+        ParseError {
+            line: self.input[..offset].matches('\n').count() + 1,
+            column: self.input[line_start..offset].chars().count() + 1,
+            snippet: snippet.to_string(),
+            width: width.max(1),
+            expected,
+            found,
+        }
+    }
+}
+
+#[test]
+fn test_source_errors() {
+    let source = Source::new("3-5\n10-x4\n7\n");
+    let mut lines = source.input().lines();
+    lines.next();
+    let line = lines.next().unwrap();
+    let (_, end) = line.split_once('-').unwrap();
+    let err = source.parse::<u64>(end, "a number").unwrap_err();
+    assert_eq!((err.line, err.column, err.width), (2, 4, 2));
+    assert_eq!(err.to_string(), "line 2, column 4: expected a number, found `x4`");
+
+    let line = lines.next().unwrap();
+    let mut fields = line.split('-');
+    let start = source.next(&mut fields, line, "a range start").unwrap();
+    let err = source.next(&mut fields, start, "`-`").unwrap_err();
+    assert_eq!(
+        err.render("2025/day5/input"),
+        "error: expected `-`, found end of line\n --> 2025/day5/input:3:2\n  |\n3 | 7\n  |  ^\n"
+    );
+}
@@ -0,0 +1,83 @@
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::parse::ParseError;
+
+/// Trait implemented by every day's puzzle solution
+///
+/// A solution parses the raw puzzle input once into `Input`, which is then
+/// shared by both parts. Each part may produce its own answer type, as long
+/// as it converts into an [`Answer`].
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts
+    type Input;
+    /// The answer type produced by part 1
+    type Answer1: Into<Answer>;
+    /// The answer type produced by part 2
+    type Answer2: Into<Answer>;
+
+    /// Whether parsing or the parts spawn threads of their own
+    ///
+    /// Their CPU time is then measured for the whole process rather than
+    /// for the thread running them.
+    const PARALLEL: bool = false;
+
+    /// Converts the raw puzzle input into `Input`, or explains what's wrong with it
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1 of the puzzle
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+
+    /// Solves part 2 of the puzzle
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+}
+
+/// Object-safe view of a [`Solution`]
+///
+/// Days with different input and answer types can be stored side by side
+/// as `&dyn AnySolution`. The parsed input is passed around as `dyn Any`
+/// and answers are converted to [`Answer`].
+pub trait AnySolution: Sync {
+    /// Parses the raw puzzle input into a type-erased `Input`
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves part 1 against input returned by [`AnySolution::parse`]
+    fn part1(&self, input: &dyn Any) -> Answer;
+
+    /// Solves part 2 against input returned by [`AnySolution::parse`]
+    fn part2(&self, input: &dyn Any) -> Answer;
+
+    /// See [`Solution::PARALLEL`]
+    fn parallel(&self) -> bool;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Answer {
+        Solution::part1(self, downcast::<S>(input)).into()
+    }
+
+    fn part2(&self, input: &dyn Any) -> Answer {
+        Solution::part2(self, downcast::<S>(input)).into()
+    }
+
+    fn parallel(&self) -> bool {
+        S::PARALLEL
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was not produced by this solution's parse")
+}
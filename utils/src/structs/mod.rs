@@ -1,2 +1,3 @@
 #![allow(dead_code)]
-pub mod stopwatch;
\ No newline at end of file
+pub mod stopwatch;
+pub mod trace;
\ No newline at end of file
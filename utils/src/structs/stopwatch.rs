@@ -1,5 +1,19 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread::ThreadId;
 use std::time::{Duration, Instant};
 
+use typed::{Running, Stopped};
+
+pub use report::{ParseDurationError, ReportFormat, Reported, Unit};
+
+pub mod clock;
+pub mod report;
+pub mod typed;
+
 /// Trait for formatting Duration values into human-readable strings
 ///
 /// Provides consistent formatting of time durations across different time scales,
@@ -7,6 +21,54 @@ use std::time::{Duration, Instant};
 pub trait ReportDuration {
     /// Converts the duration into a human-readable string representation
     fn report(&self) -> String;
+
+    /// Converts the duration into a string with the given precision
+    fn report_with(&self, precision: Precision) -> String {
+        self.report_as(ReportFormat {
+            unit: Unit::Auto,
+            precision,
+        })
+    }
+
+    /// Converts the duration into a string in the given unit and precision
+    fn report_as(&self, format: ReportFormat) -> String;
+}
+
+/// How precisely [`ReportDuration::report_with`] formats a duration
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Precision {
+    /// The format of [`ReportDuration::report`]: whole nanoseconds and
+    /// microseconds, three decimals for milliseconds and seconds
+    #[default]
+    Auto,
+    /// A fixed number of decimals for microseconds, milliseconds and seconds,
+    /// e.g. `1.234μs` with three. Extra digits are truncated, like `report` does,
+    /// and no more decimals are shown than a nanosecond resolves
+    Decimals(u8),
+    /// TSC cycles, e.g. `1234 cycles`, for wall times measured with [`clock::Tsc`]
+    ///
+    /// A [`Duration`] doesn't know how it was measured, so
+    /// [`ReportDuration`] formats it like [`Precision::Auto`]. Only
+    /// [`clock::Times`] that carry measured cycles show them.
+    Cycles,
+}
+
+impl std::str::FromStr for Precision {
+    type Err = String;
+
+    /// Parses `auto`, `cycles` or a number of decimals
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Precision::Auto),
+            "cycles" => Ok(Precision::Cycles),
+            _ => s
+                .parse()
+                .ok()
+                .filter(|&decimals| decimals <= 9)
+                .map(Precision::Decimals)
+                .ok_or_else(|| format!("precision must be auto, cycles or 0-9 decimals, got {s}")),
+        }
+    }
 }
 
 impl ReportDuration for Duration {
@@ -17,10 +79,10 @@ impl ReportDuration for Duration {
     /// # Examples
     /// ```
     /// use std::time::Duration;
-    /// use utilities::structs::stopwatch::ReportDuration;
+    /// use utils::structs::stopwatch::ReportDuration;
     ///
     /// let duration = Duration::from_secs(2);
-    /// assert_eq!(duration.report(), "2.00s");
+    /// assert_eq!(duration.report(), "2.000s");
     ///
     /// let duration = Duration::from_nanos(500);
     /// assert_eq!(duration.report(), "500ns");
@@ -37,19 +99,67 @@ impl ReportDuration for Duration {
             }
         }
     }
+
+    /// Formats the duration in `format.unit`, or the units [`ReportDuration::report`]
+    /// picks if that's [`Unit::Auto`], with `format.precision`
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use utils::structs::stopwatch::{Precision, ReportDuration, ReportFormat, Unit};
+    ///
+    /// let duration = Duration::from_nanos(1_234_567);
+    /// assert_eq!(duration.report_with(Precision::Auto), "1.234ms");
+    /// assert_eq!(duration.report_with(Precision::Decimals(5)), "1.23456ms");
+    /// assert_eq!(Duration::from_nanos(1_234).report_with(Precision::Decimals(3)), "1.234μs");
+    ///
+    /// let format = ReportFormat { unit: Unit::Micros, precision: Precision::Auto };
+    /// assert_eq!(duration.report_as(format), "1234μs");
+    /// ```
+    fn report_as(&self, format: ReportFormat) -> String {
+        let unit = match format.unit {
+            Unit::Auto => Unit::for_duration(self),
+            unit => unit,
+        };
+        let decimals = match format.precision {
+            Precision::Decimals(decimals) => decimals,
+            Precision::Auto | Precision::Cycles => unit.default_decimals(),
+        };
+        let (scale, suffix, digits) = unit.scale();
+        let nanos = self.as_nanos();
+        let decimals = (decimals as usize).min(digits);
+        if decimals == 0 {
+            return format!("{}{suffix}", nanos / scale);
+        }
+        let fraction = format!("{:0digits$}", nanos % scale);
+        format!("{}.{}{suffix}", nanos / scale, &fraction[..decimals])
+    }
 }
 
 /// A stopwatch implementation for measuring elapsed time with lap and split functionality
 ///
 /// The stopwatch can be started, stopped, reset, and queried for various timing measurements.
 /// It keeps track of total elapsed time and supports lap timing and split timing operations.
+///
+/// This checks its running state at runtime and is kept for compatibility. Prefer
+/// [`typed::Stopwatch`], where lapping a stopped watch doesn't compile.
 #[derive(Clone, Debug, Default)]
 pub struct Stopwatch {
     /// Indicates whether the stopwatch is currently running
     pub is_running: bool,
-    elapsed: Duration,
-    last_start: Option<Instant>,
-    lap_start: Option<Instant>,
+    state: State,
+}
+
+#[derive(Clone, Debug)]
+enum State {
+    Stopped(typed::Stopwatch<Stopped>),
+    Running(typed::Stopwatch<Running>),
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Stopped(typed::Stopwatch::new())
+    }
 }
 
 impl std::fmt::Display for Stopwatch {
@@ -61,21 +171,14 @@ impl std::fmt::Display for Stopwatch {
 impl Stopwatch {
     /// Creates a new stopwatch initialized to zero and not running
     pub fn new() -> Self {
-        Self {
-            is_running: false,
-            elapsed: Duration::ZERO,
-            last_start: None,
-            lap_start: None,
-        }
+        Self::default()
     }
 
-    /// Creates a new stopwatch initialized to zero and not running
+    /// Creates a new stopwatch initialized to zero and already running
     pub fn started() -> Self {
         Self {
             is_running: true,
-            elapsed: Duration::ZERO,
-            last_start: Some(Instant::now()),
-            lap_start: None,
+            state: State::Running(typed::Stopwatch::started()),
         }
     }
 
@@ -85,13 +188,13 @@ impl Stopwatch {
     /// - `true` if the stopwatch was successfully started
     /// - `false` if the stopwatch was already running
     pub fn start(&mut self) -> bool {
-        if self.is_running {
-            false
-        } else {
-            self.last_start = Some(Instant::now());
-            self.lap_start = self.last_start;
-            self.is_running = true;
-            true
+        match &self.state {
+            State::Running(_) => false,
+            State::Stopped(watch) => {
+                self.state = State::Running(watch.start());
+                self.is_running = true;
+                true
+            }
         }
     }
 
@@ -104,14 +207,11 @@ impl Stopwatch {
     /// # Returns
     /// The total elapsed time the stopwatch has been running
     pub fn stop(&mut self) -> Duration {
-        if self.is_running {
-            let now = Instant::now();
+        if let State::Running(watch) = &self.state {
+            self.state = State::Stopped(watch.stop());
             self.is_running = false;
-            self.elapsed += now - self.last_start.unwrap();
-            self.last_start = None;
-            self.lap_start = None;
         }
-        self.elapsed
+        self.elapsed()
     }
 
     /// Records a lap time and starts timing a new lap
@@ -120,13 +220,20 @@ impl Stopwatch {
     /// - If running: Duration of the completed lap
     /// - If stopped: Duration::ZERO
     pub fn lap(&mut self) -> Duration {
-        if self.is_running {
-            let now = Instant::now();
-            let lap = now - self.lap_start.unwrap_or(self.last_start.unwrap());
-            self.lap_start = Some(now);
-            lap
-        } else {
-            Duration::ZERO
+        match &mut self.state {
+            State::Running(watch) => watch.lap(),
+            State::Stopped(_) => Duration::ZERO,
+        }
+    }
+
+    /// Records a split time and starts timing a new lap
+    ///
+    /// # Returns
+    /// The total elapsed time, measured from the first start rather than the last lap
+    pub fn split(&mut self) -> Duration {
+        match &mut self.state {
+            State::Running(watch) => watch.split(),
+            State::Stopped(watch) => watch.elapsed(),
         }
     }
 
@@ -134,14 +241,12 @@ impl Stopwatch {
     /// without affecting the running state
     ///
     /// # Returns
-    /// - If running: Time since last start
-    /// - If stopped: Duration::ZERO
+    /// - If running: Time accumulated so far, including the current run
+    /// - If stopped: Time accumulated before it was stopped
     pub fn elapsed(&self) -> Duration {
-        if self.is_running {
-            let now = Instant::now();
-            self.elapsed + (now - self.last_start.unwrap())
-        } else {
-            self.elapsed
+        match &self.state {
+            State::Running(watch) => watch.elapsed(),
+            State::Stopped(watch) => watch.elapsed(),
         }
     }
 
@@ -149,9 +254,335 @@ impl Stopwatch {
     ///
     /// Clears all accumulated time and stops the stopwatch if it was running
     pub fn reset(&mut self) {
-        self.elapsed = Duration::ZERO;
-        self.last_start = None;
-        self.lap_start = None;
-        self.is_running = false;
+        *self = Self::new();
+    }
+}
+
+/// Set while a [`SpanRecorder`] is active, so [`span`] is a single load when nothing records
+static RECORDING: AtomicBool = AtomicBool::new(false);
+/// Only one recorder may run at a time
+static RECORDER_LOCK: Mutex<()> = Mutex::new(());
+/// Spans closed while recording, from every thread
+static CLOSED_SPANS: Mutex<Vec<SpanRecord>> = Mutex::new(Vec::new());
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Ids of the spans currently open on this thread, innermost last
+    static OPEN_SPANS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A named span that was opened and closed while recording
+#[derive(Clone, Debug)]
+pub struct SpanRecord {
+    pub id: u64,
+    /// Id of the span that was open on the same thread when this one started
+    pub parent: Option<u64>,
+    pub name: Cow<'static, str>,
+    pub thread: ThreadId,
+    /// Name of the thread the span ran on, if it has one
+    pub thread_name: Option<String>,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+/// RAII guard returned by [`span`], which closes the span when dropped
+#[must_use = "the span closes as soon as the guard is dropped"]
+pub struct SpanGuard {
+    open: Option<(u64, Option<u64>, Cow<'static, str>, Instant)>,
+}
+
+/// Opens a named timing span that lasts until the returned guard is dropped
+///
+/// Spans opened while another span is open on the same thread become its
+/// children. When no [`SpanRecorder`] is active this does nothing beyond
+/// checking a flag.
+///
+/// # Examples
+/// ```
+/// use utils::structs::stopwatch::{span, SpanRecorder};
+///
+/// let recorder = SpanRecorder::start();
+/// {
+///     let _outer = span("outer");
+///     let _inner = span("inner");
+/// }
+/// let report = recorder.finish();
+/// assert_eq!(report.roots[0].children[0].name, "inner");
+/// ```
+pub fn span(name: impl Into<Cow<'static, str>>) -> SpanGuard {
+    if !RECORDING.load(Ordering::Relaxed) {
+        return SpanGuard { open: None };
+    }
+    let id = NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed);
+    let parent = OPEN_SPANS.with(|open| {
+        let mut open = open.borrow_mut();
+        let parent = open.last().copied();
+        open.push(id);
+        parent
+    });
+    SpanGuard {
+        open: Some((id, parent, name.into(), Instant::now())),
     }
-}
\ No newline at end of file
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        let Some((id, parent, name, start)) = self.open.take() else {
+            return;
+        };
+        let duration = start.elapsed();
+        OPEN_SPANS.with(|open| {
+            let mut open = open.borrow_mut();
+            if let Some(index) = open.iter().rposition(|&open_id| open_id == id) {
+                open.truncate(index);
+            }
+        });
+        if RECORDING.load(Ordering::Relaxed) {
+            let thread = std::thread::current();
+            CLOSED_SPANS.lock().unwrap_or_else(PoisonError::into_inner).push(SpanRecord {
+                id,
+                parent,
+                name,
+                thread: thread.id(),
+                thread_name: thread.name().map(str::to_string),
+                start,
+                duration,
+            });
+        }
+    }
+}
+
+/// Returns whether a [`SpanRecorder`] is active
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Adds spans recorded somewhere else, e.g. by another process, to the active recorder
+///
+/// Each span gets a fresh id, and parents are renumbered to match, so they
+/// can't clash with spans recorded here. Does nothing unless a
+/// [`SpanRecorder`] is active.
+pub fn import_spans(records: Vec<SpanRecord>) {
+    if !is_recording() {
+        return;
+    }
+    let ids: HashMap<u64, u64> = records
+        .iter()
+        .map(|record| (record.id, NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed)))
+        .collect();
+    CLOSED_SPANS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .extend(records.into_iter().map(|record| SpanRecord {
+            id: ids[&record.id],
+            parent: record.parent.and_then(|parent| ids.get(&parent).copied()),
+            ..record
+        }));
+}
+
+/// Collects every span closed on any thread between [`SpanRecorder::start`] and [`SpanRecorder::finish`]
+pub struct SpanRecorder {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl SpanRecorder {
+    /// Starts recording spans, waiting for any other recorder to finish first
+    pub fn start() -> Self {
+        let lock = RECORDER_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        CLOSED_SPANS.lock().unwrap_or_else(PoisonError::into_inner).clear();
+        RECORDING.store(true, Ordering::Relaxed);
+        Self { _lock: lock }
+    }
+
+    /// Stops recording and returns the raw spans in the order they closed
+    pub fn finish_records(self) -> Vec<SpanRecord> {
+        RECORDING.store(false, Ordering::Relaxed);
+        std::mem::take(&mut *CLOSED_SPANS.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Stops recording and arranges the spans into a tree
+    pub fn finish(self) -> SpanReport {
+        SpanReport::from_records(&self.finish_records())
+    }
+}
+
+/// Timings of every span with the same name under the same parent
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanNode {
+    pub name: Cow<'static, str>,
+    /// Number of times the span was entered
+    pub calls: usize,
+    /// Time spent inside the span, including its children
+    pub total: Duration,
+    pub children: Vec<SpanNode>,
+}
+
+impl SpanNode {
+    /// Time spent inside the span but outside any of its children
+    pub fn self_time(&self) -> Duration {
+        let children: Duration = self.children.iter().map(|child| child.total).sum();
+        self.total.saturating_sub(children)
+    }
+}
+
+/// Tree of recorded spans, printed as an indented table of total and self time
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanReport {
+    /// Spans without a parent, in the order they were first entered
+    pub roots: Vec<SpanNode>,
+}
+
+impl SpanReport {
+    /// Builds the tree from raw records, merging siblings with the same name
+    pub fn from_records(records: &[SpanRecord]) -> Self {
+        let mut ordered: Vec<&SpanRecord> = records.iter().collect();
+        ordered.sort_by_key(|record| record.start);
+        let mut children: HashMap<Option<u64>, Vec<&SpanRecord>> = HashMap::new();
+        let known: HashSet<u64> = records.iter().map(|record| record.id).collect();
+        for record in ordered {
+            // Spans whose parent was still open when recording stopped become roots
+            let parent = record.parent.filter(|parent| known.contains(parent));
+            children.entry(parent).or_default().push(record);
+        }
+        Self {
+            roots: merge_siblings(&children, None),
+        }
+    }
+}
+
+fn merge_siblings(
+    children: &HashMap<Option<u64>, Vec<&SpanRecord>>,
+    parent: Option<u64>,
+) -> Vec<SpanNode> {
+    let mut nodes: Vec<SpanNode> = Vec::new();
+    let mut ids: Vec<Vec<u64>> = Vec::new();
+    for record in children.get(&parent).into_iter().flatten() {
+        match nodes.iter().position(|node| node.name == record.name) {
+            Some(index) => {
+                nodes[index].calls += 1;
+                nodes[index].total += record.duration;
+                ids[index].push(record.id);
+            }
+            None => {
+                nodes.push(SpanNode {
+                    name: record.name.clone(),
+                    calls: 1,
+                    total: record.duration,
+                    children: Vec::new(),
+                });
+                ids.push(vec![record.id]);
+            }
+        }
+    }
+    for (node, ids) in nodes.iter_mut().zip(ids) {
+        for id in ids {
+            for child in merge_siblings(children, Some(id)) {
+                match node.children.iter_mut().find(|c| c.name == child.name) {
+                    Some(existing) => merge_node(existing, child),
+                    None => node.children.push(child),
+                }
+            }
+        }
+    }
+    nodes
+}
+
+fn merge_node(into: &mut SpanNode, from: SpanNode) {
+    into.calls += from.calls;
+    into.total += from.total;
+    for child in from.children {
+        match into.children.iter_mut().find(|c| c.name == child.name) {
+            Some(existing) => merge_node(existing, child),
+            None => into.children.push(child),
+        }
+    }
+}
+
+impl std::fmt::Display for SpanReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn rows<'a>(node: &'a SpanNode, depth: usize, out: &mut Vec<(String, &'a SpanNode)>) {
+            out.push((format!("{}{}", "  ".repeat(depth), node.name), node));
+            for child in &node.children {
+                rows(child, depth + 1, out);
+            }
+        }
+        let mut out = Vec::new();
+        for root in &self.roots {
+            rows(root, 0, &mut out);
+        }
+        let width = out
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("span".len());
+        writeln!(f, "{:<width$}  {:>10}  {:>10}  {:>6}", "span", "total", "self", "calls")?;
+        for (name, node) in out {
+            writeln!(
+                f,
+                "{:<width$}  {:>10}  {:>10}  {:>6}",
+                name,
+                node.total.report(),
+                node.self_time().report(),
+                node.calls
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_span_tree() {
+    {
+        let _ignored = span("not recording");
+    }
+    let recorder = SpanRecorder::start();
+    {
+        let _day = span("day");
+        for _ in 0..3 {
+            let _edges = span("edges");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let _solve = span("solve");
+    }
+    // Imported ids may clash with the recorded ones, and are renumbered
+    let thread = std::thread::current();
+    let imported = |id, parent, name| SpanRecord {
+        id,
+        parent,
+        name: Cow::Borrowed(name),
+        thread: thread.id(),
+        thread_name: None,
+        start: Instant::now(),
+        duration: Duration::from_millis(1),
+    };
+    import_spans(vec![imported(2, Some(1), "parse"), imported(1, None, "other day")]);
+    let report = recorder.finish();
+    assert_eq!(report.roots.len(), 2);
+    assert_eq!(report.roots[1].name, "other day");
+    assert_eq!(report.roots[1].children[0].name, "parse");
+    let day = &report.roots[0];
+    assert_eq!(day.name, "day");
+    let names: Vec<(&str, usize)> = day.children.iter().map(|c| (c.name.as_ref(), c.calls)).collect();
+    assert_eq!(names, vec![("edges", 3), ("solve", 1)]);
+    assert!(day.children[0].total >= Duration::from_millis(3));
+    assert!(day.self_time() <= day.total - day.children[0].total);
+}
+
+#[test]
+fn test_compat_stopwatch() {
+    let mut watch = Stopwatch::new();
+    assert_eq!(watch.lap(), Duration::ZERO);
+    assert!(watch.start());
+    assert!(!watch.start());
+    std::thread::sleep(Duration::from_millis(1));
+    let lap = watch.lap();
+    let split = watch.split();
+    assert!(lap >= Duration::from_millis(1) && split >= lap);
+    let total = watch.stop();
+    assert!(!watch.is_running);
+    assert_eq!(watch.elapsed(), total);
+    assert_eq!(watch.lap(), Duration::ZERO);
+    watch.reset();
+    assert_eq!(watch.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,408 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+use std::sync::OnceLock;
+
+use super::{Precision, ReportDuration, ReportFormat};
+use super::typed::{Running, Stopwatch};
+
+/// A source of time readings for a [`Stopwatch`]
+///
+/// Readings are only meaningful relative to each other, the stopwatch
+/// asks the clock for the [`Duration`] between two of them.
+pub trait Clock {
+    /// A single reading of the clock
+    type Instant: Copy + fmt::Debug;
+
+    /// Reads the clock
+    fn now() -> Self::Instant;
+
+    /// Returns the time that passed between two readings, `start` being the earlier one
+    fn between(start: Self::Instant, end: Self::Instant) -> Duration;
+}
+
+/// Wall-clock time, as measured by [`Instant`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Wall;
+
+impl Clock for Wall {
+    type Instant = Instant;
+
+    fn now() -> Instant {
+        Instant::now()
+    }
+
+    fn between(start: Instant, end: Instant) -> Duration {
+        end.saturating_duration_since(start)
+    }
+}
+
+/// CPU time used by every thread of the current process
+///
+/// Unlike [`Wall`], this doesn't advance while the process is waiting for
+/// the CPU, and advances faster than wall time while several threads run.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcessCpu;
+
+/// CPU time used by the calling thread
+///
+/// A stopwatch on this clock must be lapped and stopped on the thread that started it.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadCpu;
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::ffi::{c_int, c_long};
+    use std::time::Duration;
+
+    pub const CLOCK_PROCESS_CPUTIME_ID: c_int = 2;
+    pub const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    unsafe extern "C" {
+        fn clock_gettime(clock_id: c_int, tp: *mut Timespec) -> c_int;
+    }
+
+    /// Reads one of the CPU-time clocks of `clock_gettime`
+    ///
+    /// # Panics
+    /// If the kernel rejects the clock, which it doesn't for the CPU-time clocks
+    pub fn cpu_time(clock_id: c_int) -> Duration {
+        let mut time = Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `time` is a valid, writable `struct timespec`
+        let result = unsafe { clock_gettime(clock_id, &mut time) };
+        assert_eq!(result, 0, "clock_gettime({clock_id}) failed");
+        Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Clock for ProcessCpu {
+    type Instant = Duration;
+
+    fn now() -> Duration {
+        linux::cpu_time(linux::CLOCK_PROCESS_CPUTIME_ID)
+    }
+
+    fn between(start: Duration, end: Duration) -> Duration {
+        end.saturating_sub(start)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Clock for ThreadCpu {
+    type Instant = Duration;
+
+    fn now() -> Duration {
+        linux::cpu_time(linux::CLOCK_THREAD_CPUTIME_ID)
+    }
+
+    fn between(start: Duration, end: Duration) -> Duration {
+        end.saturating_sub(start)
+    }
+}
+
+/// The CPU's time-stamp counter, read with `rdtsc`
+///
+/// Reading it costs a few nanoseconds, far less than [`Instant::now`], so it
+/// can time parts that finish in well under a microsecond. Cycles are
+/// converted to time with a frequency calibrated against [`Instant`] the
+/// first time it's needed, which takes about 10ms.
+///
+/// This assumes an invariant TSC, one that ticks at a constant rate across
+/// frequency changes and is synchronised between cores, as on any x86_64
+/// CPU from the last decade. [`Tsc::is_invariant`] checks for it.
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tsc;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+impl Tsc {
+    /// How long calibration spins for
+    const CALIBRATION: Duration = Duration::from_millis(10);
+
+    /// Reads the counter
+    pub fn read() -> u64 {
+        // SAFETY: `rdtsc` is available on every x86_64 CPU
+        unsafe { std::arch::x86_64::_rdtsc() }
+    }
+
+    /// Counter ticks per nanosecond, measured against [`Instant`] once per process
+    pub fn cycles_per_nanosecond() -> f64 {
+        static CALIBRATED: OnceLock<f64> = OnceLock::new();
+        *CALIBRATED.get_or_init(|| {
+            let start = Instant::now();
+            let first = Self::read();
+            while start.elapsed() < Self::CALIBRATION {
+                std::hint::spin_loop();
+            }
+            let cycles = Self::read().wrapping_sub(first);
+            cycles as f64 / start.elapsed().as_nanos() as f64
+        })
+    }
+
+    /// Converts a duration to counter ticks
+    pub fn cycles(duration: Duration) -> u64 {
+        (duration.as_nanos() as f64 * Self::cycles_per_nanosecond()).round() as u64
+    }
+
+    /// Returns whether `/proc/cpuinfo` reports a constant, non-stop TSC, reading it once per process
+    pub fn is_invariant() -> bool {
+        static INVARIANT: OnceLock<bool> = OnceLock::new();
+        *INVARIANT.get_or_init(|| {
+            std::fs::read_to_string("/proc/cpuinfo").is_ok_and(|cpuinfo| {
+                cpuinfo.lines().any(|line| {
+                    line.starts_with("flags")
+                        && line.contains(" constant_tsc")
+                        && line.contains(" nonstop_tsc")
+                })
+            })
+        })
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+impl Clock for Tsc {
+    type Instant = u64;
+
+    fn now() -> u64 {
+        Self::read()
+    }
+
+    fn between(start: u64, end: u64) -> Duration {
+        let cycles = end.saturating_sub(start);
+        Duration::from_nanos((cycles as f64 / Self::cycles_per_nanosecond()).round() as u64)
+    }
+}
+
+/// Wall-clock time and the CPU time used during it
+///
+/// Displays the wall time, followed by the CPU time when it's known and
+/// the parallel speedup when it reaches [`Times::PARALLEL_THRESHOLD`].
+/// With [`Precision::Cycles`], the wall time is shown in the cycles that
+/// were measured for it, if any, and the CPU time as with [`Precision::Auto`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Times {
+    pub wall: Duration,
+    /// CPU time of a thread or of the whole process, `None` on platforms without a CPU-time clock
+    pub cpu: Option<Duration>,
+    /// The wall time in [`Tsc`] cycles, `None` unless it was measured with an invariant TSC
+    pub cycles: Option<u64>,
+}
+
+impl Times {
+    /// Smallest parallel speedup worth showing, below which it's mostly noise from other threads
+    pub const PARALLEL_THRESHOLD: f64 = 1.5;
+
+    /// How many CPUs were busy on average, e.g. `4.0` for four threads working in parallel
+    pub fn parallelism(&self) -> Option<f64> {
+        let wall = self.wall.as_secs_f64();
+        self.cpu
+            .filter(|_| wall > 0.0)
+            .map(|cpu| cpu.as_secs_f64() / wall)
+    }
+}
+
+impl Times {
+    /// Formats the wall time alone, in cycles if `format` asks for them and they were measured
+    pub fn report_wall_as(&self, format: ReportFormat) -> String {
+        match (format.precision, self.cycles) {
+            (Precision::Cycles, Some(cycles)) => format!("{cycles} cycles"),
+            _ => self.wall.report_as(format),
+        }
+    }
+
+    /// Formats the times like [`Display`](fmt::Display), in `format`
+    pub fn report_as(&self, format: ReportFormat) -> String {
+        let wall = self.report_wall_as(format);
+        let Some(cpu) = self.cpu else {
+            return wall;
+        };
+        let cpu = cpu.report_as(format);
+        match self.parallelism() {
+            Some(parallelism) if parallelism >= Self::PARALLEL_THRESHOLD => {
+                format!("{wall} wall, {cpu} cpu, {parallelism:.1}x parallel")
+            }
+            _ => format!("{wall} wall, {cpu} cpu"),
+        }
+    }
+}
+
+impl fmt::Display for Times {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.report_as(ReportFormat::default()))
+    }
+}
+
+/// Laps wall-clock and CPU time together
+///
+/// [`TimesWatch::started`] measures the CPU time of the calling thread, so
+/// it isn't affected by other threads of the process, and must be lapped on
+/// that thread. Code that spawns threads of its own is measured with
+/// [`TimesWatch::started_process_wide`] instead. Where [`Tsc::is_invariant`]
+/// holds, the wall time is also counted in TSC cycles.
+///
+/// # Examples
+/// ```
+/// use utils::structs::stopwatch::clock::TimesWatch;
+///
+/// let mut watch = TimesWatch::started();
+/// let times = watch.lap();
+/// println!("took {times}");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TimesWatch {
+    wall: Stopwatch<Running>,
+    #[cfg(target_os = "linux")]
+    cpu: CpuWatch,
+    /// TSC reading at the start of the lap, `None` without an invariant TSC
+    #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+    tsc: Option<u64>,
+}
+
+/// The CPU-time stopwatch of a [`TimesWatch`]
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug)]
+enum CpuWatch {
+    Thread(Stopwatch<Running<ThreadCpu>>),
+    Process(Stopwatch<Running<ProcessCpu>>),
+}
+
+impl TimesWatch {
+    /// Creates a new watch on the calling thread's CPU time that is already running
+    pub fn started() -> Self {
+        // Checked up front, so reading /proc/cpuinfo isn't timed
+        #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+        let invariant = Tsc::is_invariant();
+        Self {
+            wall: Stopwatch::started(),
+            #[cfg(target_os = "linux")]
+            cpu: CpuWatch::Thread(Stopwatch::with_clock(ThreadCpu).start()),
+            #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+            tsc: invariant.then(Tsc::read),
+        }
+    }
+
+    /// Creates a new watch on the CPU time of every thread of the process that is already running
+    pub fn started_process_wide() -> Self {
+        #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+        let invariant = Tsc::is_invariant();
+        Self {
+            wall: Stopwatch::started(),
+            #[cfg(target_os = "linux")]
+            cpu: CpuWatch::Process(Stopwatch::with_clock(ProcessCpu).start()),
+            #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+            tsc: invariant.then(Tsc::read),
+        }
+    }
+
+    /// Records a lap on both clocks and starts timing a new lap
+    pub fn lap(&mut self) -> Times {
+        #[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+        let cycles = self.tsc.as_mut().map(|start| {
+            let now = Tsc::read();
+            now.saturating_sub(std::mem::replace(start, now))
+        });
+        #[cfg(not(all(target_arch = "x86_64", target_os = "linux")))]
+        let cycles = None;
+        #[cfg(target_os = "linux")]
+        let cpu = Some(match &mut self.cpu {
+            CpuWatch::Thread(cpu) => cpu.lap(),
+            CpuWatch::Process(cpu) => cpu.lap(),
+        });
+        #[cfg(not(target_os = "linux"))]
+        let cpu = None;
+        Times {
+            wall: self.wall.lap(),
+            cpu,
+            cycles,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_cpu_clocks() {
+    let mut process = Stopwatch::with_clock(ProcessCpu).start();
+    let mut thread = Stopwatch::with_clock(ThreadCpu).start();
+    let wall = Stopwatch::started();
+    let mut x = 0u64;
+    while wall.elapsed() < Duration::from_millis(20) {
+        x = std::hint::black_box(x.wrapping_mul(31).wrapping_add(7));
+    }
+    let busy = thread.lap();
+    assert!(busy >= Duration::from_millis(5), "thread cpu time {busy:?}");
+    assert!(process.lap() >= busy);
+
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(thread.lap() < Duration::from_millis(10));
+
+    let times = Times {
+        wall: Duration::from_millis(10),
+        cpu: Some(Duration::from_millis(40)),
+        cycles: None,
+    };
+    assert_eq!(times.to_string(), "10.000ms wall, 40.000ms cpu, 4.0x parallel");
+    let times = Times {
+        wall: Duration::from_millis(10),
+        cpu: Some(Duration::from_millis(11)),
+        cycles: None,
+    };
+    assert_eq!(times.to_string(), "10.000ms wall, 11.000ms cpu");
+
+    // Only measured cycles are shown, never for CPU time
+    let cycles = ReportFormat {
+        precision: Precision::Cycles,
+        ..ReportFormat::default()
+    };
+    assert_eq!(times.report_as(cycles), "10.000ms wall, 11.000ms cpu");
+    let times = Times {
+        cycles: Some(31_000_000),
+        ..times
+    };
+    assert_eq!(times.report_as(cycles), "31000000 cycles wall, 11.000ms cpu");
+
+    // Another thread keeping busy doesn't count towards this thread's time
+    let mut watch = TimesWatch::started();
+    let busy = std::thread::spawn(|| {
+        let wall = Stopwatch::started();
+        while wall.elapsed() < Duration::from_millis(20) {
+            std::hint::spin_loop();
+        }
+    });
+    busy.join().unwrap();
+    assert!(watch.lap().cpu.is_some_and(|cpu| cpu < Duration::from_millis(10)));
+}
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[test]
+fn test_tsc() {
+    // Calibrate up front so it isn't counted in the wall time below
+    Tsc::cycles_per_nanosecond();
+    let mut tsc = Stopwatch::with_clock(Tsc).start();
+    let wall = Stopwatch::started();
+    std::thread::sleep(Duration::from_millis(20));
+    let (tsc, wall) = (tsc.lap(), wall.elapsed());
+    let error = tsc.as_secs_f64() / wall.as_secs_f64();
+    assert!((0.9..1.1).contains(&error), "tsc {tsc:?} vs wall {wall:?}");
+    assert!(Tsc::cycles(Duration::from_micros(1)) > 0);
+
+    if Tsc::is_invariant() {
+        let mut watch = TimesWatch::started();
+        std::thread::sleep(Duration::from_millis(1));
+        let times = watch.lap();
+        let expected = Tsc::cycles(times.wall) as f64;
+        let cycles = times.cycles.expect("an invariant TSC is used") as f64;
+        assert!((0.5..2.0).contains(&(cycles / expected)), "{cycles} cycles for {:?}", times.wall);
+    }
+}
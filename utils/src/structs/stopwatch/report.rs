@@ -0,0 +1,262 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{Precision, ReportDuration};
+
+/// Environment variable holding the default [`ReportFormat`], e.g. `unit=μs precision=3`
+pub const REPORT_FORMAT_VAR: &str = "AOC_REPORT";
+
+/// Unit that [`ReportDuration::report_as`] formats a duration in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Unit {
+    /// The largest unit that keeps the whole part non-zero, as [`ReportDuration::report`] does
+    #[default]
+    Auto,
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+}
+
+impl Unit {
+    /// Nanoseconds per unit, suffix, and the number of fraction digits down to a nanosecond
+    pub(super) fn scale(self) -> (u128, &'static str, usize) {
+        match self {
+            Unit::Auto | Unit::Nanos => (1, "ns", 0),
+            Unit::Micros => (1_000, "μs", 3),
+            Unit::Millis => (1_000_000, "ms", 6),
+            Unit::Secs => (1_000_000_000, "s", 9),
+        }
+    }
+
+    /// The unit [`Unit::Auto`] picks for `duration`
+    pub(super) fn for_duration(duration: &Duration) -> Self {
+        match duration.as_nanos() {
+            ..1_000 => Unit::Nanos,
+            1_000..1_000_000 => Unit::Micros,
+            1_000_000..1_000_000_000 => Unit::Millis,
+            1_000_000_000.. => Unit::Secs,
+        }
+    }
+
+    /// Decimals [`Precision::Auto`] shows in this unit
+    pub(super) fn default_decimals(self) -> u8 {
+        match self {
+            Unit::Auto | Unit::Nanos | Unit::Micros => 0,
+            Unit::Millis | Unit::Secs => 3,
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Unit::Auto),
+            "ns" => Ok(Unit::Nanos),
+            "μs" | "µs" | "us" => Ok(Unit::Micros),
+            "ms" => Ok(Unit::Millis),
+            "s" => Ok(Unit::Secs),
+            _ => Err(format!("unit must be auto, ns, μs, ms or s, got {s}")),
+        }
+    }
+}
+
+/// Unit and precision used when reporting durations
+///
+/// Parsed from whitespace or comma separated `key=value` pairs, e.g.
+/// `unit=ms, precision=6`, with keys `unit` (see [`Unit`]) and `precision`
+/// (see [`Precision`]). Missing keys keep their defaults, which format
+/// like [`ReportDuration::report`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReportFormat {
+    pub unit: Unit,
+    pub precision: Precision,
+}
+
+impl ReportFormat {
+    /// Reads the format from [`REPORT_FORMAT_VAR`], or the default if it isn't set
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(REPORT_FORMAT_VAR) {
+            Ok(format) => format
+                .parse()
+                .map_err(|err| format!("{REPORT_FORMAT_VAR}: {err}")),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut format = ReportFormat::default();
+        for setting in s.split([',', ' ', '\t', '\n']).filter(|setting| !setting.is_empty()) {
+            match setting.split_once('=') {
+                Some(("unit", unit)) => format.unit = unit.parse()?,
+                Some(("precision", precision)) => format.precision = precision.parse()?,
+                _ => return Err(format!("expected unit=<unit> or precision=<precision>, got {setting}")),
+            }
+        }
+        Ok(format)
+    }
+}
+
+/// A [`Duration`] written the way [`ReportDuration`] writes it
+///
+/// Parses every format the `report` methods produce, such as `500ns`,
+/// `42μs`, `1.234ms`, `2.000s` or `1.23456ms`, and `us` in place of `μs`.
+/// `<n> cycles` is parsed with the calibrated [`super::clock::Tsc`] where
+/// it exists. Parsing is exact, so a report round-trips to the original
+/// duration truncated to the precision it was shown with. Displays like
+/// [`ReportDuration::report`].
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use utils::structs::stopwatch::{ReportDuration, Reported};
+///
+/// let Reported(duration) = "1.234ms".parse().unwrap();
+/// assert_eq!(duration, Duration::from_micros(1_234));
+/// assert_eq!(duration.report().parse::<Reported>().unwrap().0, duration);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Reported(pub Duration);
+
+/// Error returned when a string isn't a duration in a [`ReportDuration`] format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDurationError {
+    pub input: String,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid duration {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+impl FromStr for Reported {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseDurationError {
+            input: s.to_string(),
+            reason,
+        };
+        let trimmed = s.trim();
+        if let Some(cycles) = trimmed.strip_suffix("cycles") {
+            let cycles: u64 = cycles
+                .trim()
+                .parse()
+                .map_err(|_| error("expected a whole number of cycles"))?;
+            return cycles_to_duration(cycles)
+                .map(Reported)
+                .ok_or_else(|| error("cycles need a TSC clock"));
+        }
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| error("missing unit"))?;
+        let (number, unit) = trimmed.split_at(split);
+        let unit = match unit.parse() {
+            Ok(Unit::Auto) | Err(_) => return Err(error("unknown unit")),
+            Ok(unit) => unit,
+        };
+        let (scale, _, digits) = unit.scale();
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() || (number.contains('.') && fraction.is_empty()) {
+            return Err(error("expected a number before the unit"));
+        }
+        if fraction.len() > digits {
+            return Err(error("more precise than a nanosecond"));
+        }
+        let whole: u128 = whole.parse().map_err(|_| error("expected a number before the unit"))?;
+        let fraction: u128 = if fraction.is_empty() {
+            0
+        } else {
+            let padded = format!("{fraction:0<digits$}");
+            padded.parse().map_err(|_| error("expected a number before the unit"))?
+        };
+        let nanos = whole
+            .checked_mul(scale)
+            .and_then(|nanos| nanos.checked_add(fraction))
+            .and_then(|nanos| u64::try_from(nanos).ok())
+            .ok_or_else(|| error("too long"))?;
+        Ok(Reported(Duration::from_nanos(nanos)))
+    }
+}
+
+impl fmt::Display for Reported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.report())
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+fn cycles_to_duration(cycles: u64) -> Option<Duration> {
+    let nanos = cycles as f64 / super::clock::Tsc::cycles_per_nanosecond();
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+#[cfg(not(all(target_arch = "x86_64", target_os = "linux")))]
+fn cycles_to_duration(_cycles: u64) -> Option<Duration> {
+    None
+}
+
+#[test]
+fn test_round_trip() {
+    let durations = [
+        0,
+        7,
+        999,
+        1_000,
+        1_234,
+        999_999,
+        1_234_567,
+        999_999_999,
+        2_000_000_000,
+        61_234_567_891,
+    ];
+    for nanos in durations.map(Duration::from_nanos) {
+        let truncate = |unit: u64| Duration::from_nanos(nanos.as_nanos() as u64 / unit * unit);
+        let expected = match nanos.as_nanos() {
+            ..1_000 => nanos,
+            1_000..1_000_000 => truncate(1_000),
+            1_000_000..1_000_000_000 => truncate(1_000),
+            1_000_000_000.. => truncate(1_000_000),
+        };
+        assert_eq!(nanos.report().parse::<Reported>().unwrap().0, expected, "{}", nanos.report());
+        for unit in [Unit::Auto, Unit::Nanos, Unit::Micros, Unit::Millis, Unit::Secs] {
+            let exact = nanos.report_as(ReportFormat {
+                unit,
+                precision: Precision::Decimals(9),
+            });
+            assert_eq!(exact.parse::<Reported>().unwrap().0, nanos, "{exact}");
+        }
+    }
+    assert_eq!("5us".parse::<Reported>().unwrap().0, Duration::from_micros(5));
+    assert_eq!("1.0001μs".parse::<Reported>().unwrap_err().reason, "more precise than a nanosecond");
+    assert!("ms".parse::<Reported>().is_err());
+    assert!("12".parse::<Reported>().is_err());
+}
+
+#[test]
+fn test_report_format() {
+    let format: ReportFormat = "unit=μs, precision=2".parse().unwrap();
+    assert_eq!(
+        format,
+        ReportFormat {
+            unit: Unit::Micros,
+            precision: Precision::Decimals(2),
+        }
+    );
+    assert_eq!(Duration::from_nanos(1_234_567).report_as(format), "1234.56μs");
+    // A plain duration has no measured cycles to show
+    assert_eq!(Duration::from_nanos(1_234_567).report_with(Precision::Cycles), "1.234ms");
+    assert_eq!("".parse::<ReportFormat>().unwrap(), ReportFormat::default());
+    assert!("unit=hours".parse::<ReportFormat>().is_err());
+}
@@ -0,0 +1,158 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use super::ReportDuration;
+use super::clock::{Clock, Wall};
+
+/// Marker for a [`Stopwatch`] that is currently timing on clock `C`
+#[derive(Clone, Copy, Debug)]
+pub struct Running<C: Clock = Wall> {
+    run_start: C::Instant,
+    lap_start: C::Instant,
+}
+
+/// Marker for a [`Stopwatch`] on clock `C` that is paused or has never been started
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stopped<C: Clock = Wall>(PhantomData<C>);
+
+/// A stopwatch whose running state is part of its type
+///
+/// Operations that only make sense while timing, such as [`Stopwatch::lap`]
+/// and [`Stopwatch::split`], exist only on `Stopwatch<Running>`, so using
+/// them on a stopped watch is a compile error rather than a silent
+/// `Duration::ZERO`. Starting and stopping consume the watch and return it
+/// in its new state.
+///
+/// Watches measure wall-clock time unless created with
+/// [`Stopwatch::with_clock`], e.g. on a CPU-time clock from [`super::clock`].
+///
+/// # Examples
+/// ```
+/// use utils::structs::stopwatch::typed::Stopwatch;
+///
+/// let mut watch = Stopwatch::started();
+/// let first = watch.lap();
+/// let total = watch.split();
+/// assert!(total >= first);
+/// let watch = watch.stop();
+/// assert!(watch.elapsed() >= total);
+/// ```
+///
+/// ```compile_fail
+/// use utils::structs::stopwatch::typed::Stopwatch;
+///
+/// let mut watch = Stopwatch::new();
+/// watch.lap();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Stopwatch<State> {
+    /// Time accumulated by runs that have already been stopped
+    elapsed: Duration,
+    state: State,
+}
+
+impl Default for Stopwatch<Stopped> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stopwatch<Stopped> {
+    /// Creates a new wall-clock stopwatch initialized to zero and not running
+    pub fn new() -> Self {
+        Self::with_clock(Wall)
+    }
+}
+
+impl<C: Clock> Stopwatch<Stopped<C>> {
+    /// Creates a new stopwatch on `clock`, initialized to zero and not running
+    pub fn with_clock(_clock: C) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            state: Stopped(PhantomData),
+        }
+    }
+
+    /// Starts timing, keeping the time accumulated so far
+    pub fn start(self) -> Stopwatch<Running<C>> {
+        let now = C::now();
+        Stopwatch {
+            elapsed: self.elapsed,
+            state: Running {
+                run_start: now,
+                lap_start: now,
+            },
+        }
+    }
+
+    /// Returns the total time the stopwatch has been running
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Clears the accumulated time
+    pub fn reset(self) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            state: self.state,
+        }
+    }
+}
+
+impl Stopwatch<Running> {
+    /// Creates a new wall-clock stopwatch initialized to zero and already running
+    pub fn started() -> Self {
+        Stopwatch::new().start()
+    }
+}
+
+impl<C: Clock> Stopwatch<Running<C>> {
+    /// Records a lap time and starts timing a new lap
+    ///
+    /// # Returns
+    /// The time since the last lap or split, or since the watch was started
+    pub fn lap(&mut self) -> Duration {
+        let now = C::now();
+        let lap = C::between(self.state.lap_start, now);
+        self.state.lap_start = now;
+        lap
+    }
+
+    /// Records a split time and starts timing a new lap
+    ///
+    /// Unlike [`Stopwatch::lap`], a split is measured from the very start
+    /// rather than from the previous lap.
+    ///
+    /// # Returns
+    /// The total time the stopwatch has been running, including earlier runs
+    pub fn split(&mut self) -> Duration {
+        let now = C::now();
+        self.state.lap_start = now;
+        self.elapsed + C::between(self.state.run_start, now)
+    }
+
+    /// Returns the total time the stopwatch has been running, without recording a lap
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + C::between(self.state.run_start, C::now())
+    }
+
+    /// Stops timing and adds the current run to the accumulated time
+    pub fn stop(self) -> Stopwatch<Stopped<C>> {
+        Stopwatch {
+            elapsed: self.elapsed(),
+            state: Stopped(PhantomData),
+        }
+    }
+}
+
+impl<C: Clock> std::fmt::Display for Stopwatch<Running<C>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.elapsed().report())
+    }
+}
+
+impl<C: Clock> std::fmt::Display for Stopwatch<Stopped<C>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.elapsed().report())
+    }
+}
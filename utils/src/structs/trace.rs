@@ -0,0 +1,91 @@
+use std::fmt::Write;
+use std::thread::ThreadId;
+
+use super::stopwatch::SpanRecord;
+
+/// Renders spans as Chrome `trace_event` JSON
+///
+/// The output loads in `chrome://tracing` or Perfetto. Each span becomes a
+/// complete (`"X"`) event, timestamps are in microseconds from the earliest
+/// span, and every thread that recorded spans gets its own track, named
+/// after the thread when it has a name.
+pub fn chrome_trace(records: &[SpanRecord]) -> String {
+    let Some(origin) = records.iter().map(|record| record.start).min() else {
+        return "{\"traceEvents\":[]}\n".to_string();
+    };
+
+    let mut threads: Vec<(ThreadId, Option<&str>)> = Vec::new();
+    let mut events = Vec::with_capacity(records.len());
+    let mut ordered: Vec<&SpanRecord> = records.iter().collect();
+    ordered.sort_by_key(|record| record.start);
+    for record in ordered {
+        let tid = match threads.iter().position(|(id, _)| *id == record.thread) {
+            Some(index) => index + 1,
+            None => {
+                threads.push((record.thread, record.thread_name.as_deref()));
+                threads.len()
+            }
+        };
+        let ts = (record.start - origin).as_nanos() as f64 / 1_000.0;
+        let dur = record.duration.as_nanos() as f64 / 1_000.0;
+        events.push(format!(
+            "{{\"name\":\"{}\",\"cat\":\"aoc\",\"ph\":\"X\",\"ts\":{ts:.3},\"dur\":{dur:.3},\"pid\":1,\"tid\":{tid}}}",
+            escape(&record.name)
+        ));
+    }
+    for (index, (_, name)) in threads.iter().enumerate() {
+        let name = match name {
+            Some(name) => escape(name),
+            None => format!("thread {}", index + 1),
+        };
+        events.push(format!(
+            "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":1,\"tid\":{},\"args\":{{\"name\":\"{name}\"}}}}",
+            index + 1
+        ));
+    }
+
+    let mut json = String::from("{\"traceEvents\":[\n");
+    json.push_str(&events.join(",\n"));
+    json.push_str("\n],\"displayTimeUnit\":\"ns\"}\n");
+    json
+}
+
+/// Escapes a string for use inside a JSON string literal
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[test]
+fn test_chrome_trace() {
+    use super::stopwatch::{SpanRecorder, span};
+
+    let recorder = SpanRecorder::start();
+    {
+        let _main = span("main \"thread\"");
+        std::thread::Builder::new()
+            .name("worker".to_string())
+            .spawn(|| {
+                let _worker = span("worker span");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+    let trace = chrome_trace(&recorder.finish_records());
+    assert!(trace.starts_with("{\"traceEvents\":[\n"));
+    assert!(trace.contains("\"name\":\"main \\\"thread\\\"\",\"cat\":\"aoc\",\"ph\":\"X\",\"ts\":"));
+    assert!(trace.contains("\"name\":\"worker span\""));
+    assert!(trace.contains("\"args\":{\"name\":\"worker\"}"));
+}